@@ -0,0 +1,294 @@
+//! Procedural macros for `checked_num`.
+//!
+//! Enable the `macros` feature of `checked_num` and use them through its re-exports.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{
+    Expr, ImplItem, Item, Token, Type,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    visit_mut::VisitMut,
+};
+
+use rewrite::Rewriter;
+
+mod rewrite;
+
+/// Evaluates an integer expression with every operation checked.
+///
+/// Each variable, literal and sub-expression is turned into a `CheckedNum`,
+/// so precedence can't sneak an unchecked operation into the calculation.
+/// The integer type is inferred from the operands, which all have to share it.
+/// An explicit type can be given as a prefix: `checked!(u32: ...)`.
+///
+/// Arithmetic that would have to stay unchecked, e.g. in an index or in a cast,
+/// is rejected at compile time.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedU16, checked};
+///
+/// let (a, b, c, d) = (1u16, 300, 300, 2);
+///
+/// // `b * c` overflows before it is added to a `CheckedNum`.
+/// // let unchecked = CheckedU16::new(a) + b * c - d;
+///
+/// assert!(checked!(a + b * c - d).did_overflow());
+/// assert_eq!(checked!(a + b - d), 299);
+///
+/// // Literals take the type of the other operands.
+/// assert_eq!(checked!(a * 2 + 1), 3);
+///
+/// // Or an explicit type.
+/// assert!(checked!(u8: 200 + 100).did_overflow());
+///
+/// // `CheckedNum` operands are accepted as well.
+/// let e = CheckedU16::new(7);
+/// assert_eq!(checked!(e * b), 2100);
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked;
+///
+/// let values = [1u32, 2, 3];
+/// let i = 1usize;
+///
+/// // The index must be a builtin integer.
+/// checked!(values[i + 1] * 2);
+/// ```
+#[proc_macro]
+pub fn checked(input: TokenStream) -> TokenStream {
+    let CheckedInput { ty, expr } = parse_macro_input!(input as CheckedInput);
+
+    let mut rewriter = Rewriter::new(ty.clone());
+    let checked = rewriter.checked(expr);
+
+    if let Err(error) = rewriter.finish() {
+        let error = error.into_compile_error();
+        return quote!({ #error }).into();
+    }
+
+    annotate(ty, checked).into()
+}
+
+/// Evaluates an integer expression like [`checked!`] and explains a failure.
+///
+/// Returns the value, or an `OverflowReport` with the first failing sub-expression,
+/// its source text and the values of its operands.
+///
+/// Example:
+/// ```rust
+/// use checked_num::explain;
+///
+/// let (qty, unit_price, fee) = (3_000_000_000u32, 2, 5);
+///
+/// assert_eq!(explain!(qty + fee).ok(), Some(3_000_000_005));
+///
+/// let report = explain!(fee + qty * unit_price).unwrap_err();
+///
+/// // attempt to multiply with overflow in `qty * unit_price` (qty = 3000000000, unit_price = 2)
+/// assert!(report.to_string().starts_with("attempt to multiply with overflow"));
+/// assert!(report.to_string().ends_with("in `qty * unit_price` (qty = 3000000000, unit_price = 2)"));
+/// ```
+#[proc_macro]
+pub fn explain(input: TokenStream) -> TokenStream {
+    let CheckedInput { ty, expr } = parse_macro_input!(input as CheckedInput);
+
+    let source = rewrite::source(&expr);
+    let explainer = Ident::new("explainer", Span::mixed_site());
+    let result = Ident::new("result", Span::mixed_site());
+
+    let mut rewriter = Rewriter::explaining(ty.clone(), explainer.clone());
+    let checked = rewriter.checked(expr);
+
+    if let Err(error) = rewriter.finish() {
+        let error = error.into_compile_error();
+        return quote!({ #error }).into();
+    }
+
+    let checked = annotate(ty, checked);
+
+    quote!({
+        let #explainer = ::checked_num::__private::Explainer::new(#source);
+        let #result = #checked;
+        #explainer.finish(#result)
+    })
+    .into()
+}
+
+/// Checks all integer arithmetic in a function, or in every method of an impl block.
+///
+/// Every arithmetic operator (`+`, `-`, `*`, `/`, `%`, `<<`, `>>`, unary `-`
+/// and their `_Assign` variants) in the body is rewritten like in [`checked!`],
+/// so all results are `CheckedNum`s. Compound assignments require a `CheckedNum` on the left.
+/// The receivers of `pow`, `abs`, `div_euclid` and `rem_euclid` are made checked as well.
+///
+/// Arithmetic that can't be checked is a compile error:
+/// unchecked integer methods like `wrapping_add`, `saturating_mul`, `strict_add` or `add`,
+/// methods that panic on overflow like `next_multiple_of`, `isqrt` or `ilog2`,
+/// `sum` and `product` (use `CheckedIterExt` instead),
+/// calls like `u32::pow(base, exp)`, whose receiver can't be checked,
+/// arithmetic in indices, casts and range bounds,
+/// and arithmetic in macro invocations whose arguments are not expressions.
+/// Nested items and constant contexts are left untouched.
+///
+/// Only integer arithmetic can be checked, so float and string operators
+/// are compile errors as well. Move them into a separate function.
+///
+/// Named `checked_fn` as attribute macros share a namespace with `checked!`.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedU32, checked_fn};
+///
+/// const FEE: u32 = 5;
+///
+/// #[checked_fn]
+/// fn price(qty: u32, unit: u32) -> CheckedU32 {
+///     qty * unit + FEE
+/// }
+///
+/// assert_eq!(price(3, 4), 17);
+/// assert!(price(u32::MAX, 2).did_overflow());
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked_fn;
+///
+/// #[checked_fn]
+/// fn price(qty: u32, unit: u32) -> u32 {
+///     qty.wrapping_mul(unit)
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked_fn;
+///
+/// #[checked_fn]
+/// fn power(base: u32, exp: u32) -> u32 {
+///     u32::pow(base, exp)
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked_fn;
+///
+/// #[checked_fn]
+/// fn total(prices: &[u32]) -> u32 {
+///     prices.iter().sum()
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked_fn;
+///
+/// #[checked_fn]
+/// fn rounded(qty: u32, lot: u32) -> u32 {
+///     qty.next_multiple_of(lot)
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked_fn;
+///
+/// #[checked_fn]
+/// fn next(qty: u32) -> u32 {
+///     qty.strict_add(1)
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked_fn;
+///
+/// #[checked_fn]
+/// fn side(area: u32) -> u32 {
+///     area.isqrt()
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked_fn;
+///
+/// #[checked_fn]
+/// fn magnitude(qty: u32) -> u32 {
+///     qty.ilog10()
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// use checked_num::checked_fn;
+///
+/// #[checked_fn]
+/// fn average(total: f64, count: f64) -> f64 {
+///     total / count
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn checked_fn(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return syn::Error::new_spanned(attr, "`checked_fn` does not take arguments")
+            .into_compile_error()
+            .into();
+    }
+
+    let mut item = parse_macro_input!(item as Item);
+    let mut rewriter = Rewriter::new(None);
+
+    match &mut item {
+        Item::Fn(function) => rewriter.visit_block_mut(&mut function.block),
+        Item::Impl(block) => {
+            for impl_item in &mut block.items {
+                if let ImplItem::Fn(method) = impl_item {
+                    rewriter.visit_block_mut(&mut method.block);
+                }
+            }
+        }
+        item => {
+            return syn::Error::new_spanned(item, "expected a function or an impl block")
+                .into_compile_error()
+                .into();
+        }
+    }
+
+    let error = rewriter.finish().err().map(syn::Error::into_compile_error);
+
+    quote!(#item #error).into()
+}
+
+/// Enforces the type given as `checked!(Type: ...)` prefix.
+fn annotate(ty: Option<Type>, checked: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    match ty {
+        Some(ty) => quote!(::core::convert::identity::<::checked_num::CheckedNum<#ty>>(#checked)),
+        None => checked,
+    }
+}
+
+/// `checked!` input: an optional `Type:` prefix followed by an expression.
+struct CheckedInput {
+    ty: Option<Type>,
+    expr: Expr,
+}
+
+impl Parse for CheckedInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        let has_type =
+            fork.parse::<Type>().is_ok() && fork.peek(Token![:]) && !fork.peek(Token![::]);
+
+        let ty = if has_type {
+            let ty = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Some(ty)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            ty,
+            expr: input.parse()?,
+        })
+    }
+}
@@ -0,0 +1,562 @@
+use core::{iter, mem};
+
+use proc_macro2::{Delimiter, Group, Ident, LineColumn, Span, TokenStream};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{
+    BinOp, Expr, ExprBinary, ExprLit, ExprUnary, Lit, Macro, Token, Type, UnOp,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
+};
+
+/// Rewrites integer arithmetic into `CheckedNum` operations.
+///
+/// Literals stay raw as long as they are combined with a checked operand,
+/// so they take the integer type of that operand.
+/// Every other operand is passed through `__private::into_checked`.
+pub struct Rewriter {
+    /// The integer type of literals that are not combined with a checked operand.
+    ty: Option<Type>,
+    /// A `__private::Explainer` that is notified of the result of every operation.
+    explainer: Option<Ident>,
+    errors: Vec<syn::Error>,
+}
+
+/// A rewritten operand of an arithmetic operator.
+enum Operand {
+    /// Evaluates to a `CheckedNum`.
+    Checked(TokenStream),
+    /// An integer literal, possibly negated.
+    Literal(TokenStream),
+}
+
+impl Rewriter {
+    pub fn new(ty: Option<Type>) -> Self {
+        Self {
+            ty,
+            explainer: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Like [`Rewriter::new`], but reports every operation to `explainer`.
+    pub fn explaining(ty: Option<Type>, explainer: Ident) -> Self {
+        Self {
+            explainer: Some(explainer),
+            ..Self::new(ty)
+        }
+    }
+
+    /// Rewrites `expr` into an expression evaluating to a `CheckedNum`.
+    pub fn checked(&mut self, expr: Expr) -> TokenStream {
+        let operand = self.operand(expr);
+        self.force_checked(operand)
+    }
+
+    /// Returns all errors found while rewriting, combined into one.
+    pub fn finish(self) -> syn::Result<()> {
+        let mut errors = self.errors.into_iter();
+
+        match errors.next() {
+            Some(mut error) => {
+                error.extend(errors);
+                Err(error)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn operand(&mut self, expr: Expr) -> Operand {
+        match expr {
+            Expr::Binary(ExprBinary {
+                attrs,
+                left,
+                op,
+                right,
+            }) if is_arithmetic(&op) => {
+                let sources = [
+                    source(&quote!(#left #op #right)),
+                    source(&left),
+                    source(&right),
+                ];
+
+                let left = self.operand(*left);
+                let right = self.operand(*right);
+
+                let left = match (left, &right) {
+                    (Operand::Literal(left), Operand::Literal(_)) => self.wrap_literal(left),
+                    (Operand::Checked(left) | Operand::Literal(left), _) => left,
+                };
+                let right = right.into_tokens();
+
+                Operand::Checked(match &self.explainer {
+                    // Parenthesized operands keep their parentheses, which are unnecessary in a `let`.
+                    Some(explainer) => {
+                        let lhs = Ident::new("lhs", Span::mixed_site().located_at(op.span()));
+                        let rhs = Ident::new("rhs", Span::mixed_site().located_at(op.span()));
+
+                        quote!({
+                            #[allow(unused_parens)]
+                            let #lhs = #left;
+                            #[allow(unused_parens)]
+                            let #rhs = #right;
+                            #explainer.binary(#lhs #op #rhs, &#lhs, &#rhs, [#(#sources),*])
+                        })
+                    }
+                    None => quote!(#(#attrs)* #left #op #right),
+                })
+            }
+            Expr::Unary(ExprUnary {
+                attrs,
+                op: op @ UnOp::Neg(_),
+                expr,
+            }) => {
+                let sources = [source(&quote!(#op #expr)), source(&expr)];
+
+                match (self.operand(*expr), &self.explainer) {
+                    (Operand::Literal(expr), _) => Operand::Literal(quote!(#(#attrs)* #op #expr)),
+                    (Operand::Checked(expr), Some(explainer)) => {
+                        let operand =
+                            Ident::new("operand", Span::mixed_site().located_at(op.span()));
+
+                        Operand::Checked(quote!({
+                            #[allow(unused_parens)]
+                            let #operand = #expr;
+                            #explainer.unary(#op #operand, &#operand, [#(#sources),*])
+                        }))
+                    }
+                    (Operand::Checked(expr), None) => {
+                        Operand::Checked(quote!(#(#attrs)* #op #expr))
+                    }
+                }
+            }
+            Expr::Paren(paren) => {
+                let span = paren.paren_token.span.join();
+                self.operand(*paren.expr).map(|inner| {
+                    let mut group = Group::new(Delimiter::Parenthesis, inner);
+                    group.set_span(span);
+                    group.into_token_stream()
+                })
+            }
+            Expr::Group(group) => self
+                .operand(*group.expr)
+                .map(|inner| Group::new(Delimiter::None, inner).into_token_stream()),
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(_), ..
+            }) => Operand::Literal(expr.into_token_stream()),
+            Expr::MethodCall(mut call) if is_checked_method(&call.method.to_string()) => {
+                let sources = [source(&call), source(&call.receiver), source(&call.args)];
+
+                let receiver = mem::replace(&mut *call.receiver, placeholder());
+                *call.receiver = Expr::Verbatim(self.checked(receiver));
+
+                for arg in &mut call.args {
+                    self.visit_expr_mut(arg);
+                }
+
+                Operand::Checked(match &self.explainer {
+                    Some(explainer) if call.args.len() == 1 => {
+                        let span = Span::mixed_site().located_at(call.method.span());
+                        let base = Ident::new("base", span);
+                        let exp = Ident::new("exp", span);
+                        let (receiver, method, arg) = (&call.receiver, &call.method, &call.args);
+
+                        quote!({
+                            #[allow(unused_parens)]
+                            let #base = #receiver;
+                            #[allow(unused_parens)]
+                            let #exp = #arg;
+                            #explainer.binary(#base.#method(#exp), &#base, &#exp, [#(#sources),*])
+                        })
+                    }
+                    _ => call.into_token_stream(),
+                })
+            }
+            mut expr => {
+                let leaf_source = source(&expr);
+                self.visit_expr_mut(&mut expr);
+
+                let checked = quote_spanned! {expr.span()=>
+                    ::checked_num::__private::into_checked(#expr)
+                };
+
+                Operand::Checked(match &self.explainer {
+                    Some(explainer) => quote!(#explainer.leaf(#checked, #leaf_source)),
+                    None => checked,
+                })
+            }
+        }
+    }
+
+    fn force_checked(&self, operand: Operand) -> TokenStream {
+        match operand {
+            Operand::Checked(tokens) => tokens,
+            Operand::Literal(literal) => self.wrap_literal(literal),
+        }
+    }
+
+    fn wrap_literal(&self, literal: TokenStream) -> TokenStream {
+        let span = literal.span();
+
+        match &self.ty {
+            Some(ty) => quote_spanned!(span=> ::checked_num::CheckedNum::<#ty>::new(#literal)),
+            None => quote_spanned!(span=> ::checked_num::CheckedNum::new(#literal)),
+        }
+    }
+
+    /// Reports an error if `expr` contains arithmetic, which would have to stay unchecked
+    /// because the surrounding expression requires a builtin integer.
+    fn forbid_arithmetic(&mut self, expr: &mut Expr, place: &str) {
+        let mut finder = ArithmeticFinder::default();
+        finder.visit_expr(expr);
+
+        match finder.found {
+            Some(span) => self.errors.push(syn::Error::new(
+                span,
+                format!(
+                    "arithmetic in {place} cannot be checked, \
+                     compute it with `checked!` and unwrap the result first"
+                ),
+            )),
+            None => self.visit_expr_mut(expr),
+        }
+    }
+}
+
+impl Operand {
+    fn map(self, f: impl FnOnce(TokenStream) -> TokenStream) -> Self {
+        match self {
+            Operand::Checked(tokens) => Operand::Checked(f(tokens)),
+            Operand::Literal(tokens) => Operand::Literal(f(tokens)),
+        }
+    }
+
+    fn into_tokens(self) -> TokenStream {
+        match self {
+            Operand::Checked(tokens) | Operand::Literal(tokens) => tokens,
+        }
+    }
+}
+
+impl VisitMut for Rewriter {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Binary(binary) if is_arithmetic(&binary.op) => {
+                let binary = mem::replace(expr, placeholder());
+                *expr = Expr::Verbatim(self.checked(binary));
+            }
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) && !is_literal(&unary.expr) => {
+                let unary = mem::replace(expr, placeholder());
+                *expr = Expr::Verbatim(self.checked(unary));
+            }
+            Expr::MethodCall(call) if is_checked_method(&call.method.to_string()) => {
+                let call = mem::replace(expr, placeholder());
+                *expr = Expr::Verbatim(self.checked(call));
+            }
+            Expr::Binary(binary) if is_arithmetic_assign(&binary.op) => {
+                self.visit_expr_mut(&mut binary.left);
+
+                let right = mem::replace(&mut *binary.right, placeholder());
+                *binary.right = Expr::Verbatim(self.checked(right));
+            }
+            Expr::MethodCall(call) if is_unchecked_method(&call.method.to_string()) => {
+                let method = call.method.to_string();
+                self.errors.push(syn::Error::new(
+                    call.method.span(),
+                    format!("`{method}` cannot be checked, {}", replacement(&method)),
+                ));
+                visit_mut::visit_expr_method_call_mut(self, call);
+            }
+            Expr::Call(call) => {
+                if let Some(method) = unchecked_function(&call.func) {
+                    self.errors.push(syn::Error::new(
+                        call.func.span(),
+                        format!("this function cannot be checked, {}", replacement(&method)),
+                    ));
+                }
+                visit_mut::visit_expr_call_mut(self, call);
+            }
+            Expr::Index(index) => {
+                self.visit_expr_mut(&mut index.expr);
+                self.forbid_arithmetic(&mut index.index, "an index");
+            }
+            Expr::Range(range) => {
+                for bound in [&mut range.start, &mut range.end].into_iter().flatten() {
+                    self.forbid_arithmetic(bound, "a range bound");
+                }
+            }
+            Expr::Cast(cast) => self.forbid_arithmetic(&mut cast.expr, "a cast"),
+            // The length is a constant, overflowing it is a compile error.
+            Expr::Repeat(repeat) => self.visit_expr_mut(&mut repeat.expr),
+            _ => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        if is_opaque_macro(mac) {
+            return;
+        }
+
+        // Most macros taking expressions take a comma separated list of them.
+        match mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            Ok(mut args) => {
+                for arg in &mut args {
+                    self.visit_expr_mut(arg);
+                }
+
+                mac.tokens = args.into_token_stream();
+            }
+            Err(_) => {
+                if let Some(span) = find_arithmetic_tokens(mac.tokens.clone()) {
+                    self.errors.push(syn::Error::new(
+                        span,
+                        "arithmetic inside this macro invocation cannot be checked",
+                    ));
+                }
+            }
+        }
+    }
+
+    // Nested items, types, patterns and const contexts are evaluated at compile time
+    // or have their own semantics; they are left untouched.
+    fn visit_item_mut(&mut self, _: &mut syn::Item) {}
+
+    fn visit_type_mut(&mut self, _: &mut Type) {}
+
+    fn visit_pat_mut(&mut self, _: &mut syn::Pat) {}
+
+    fn visit_generic_argument_mut(&mut self, _: &mut syn::GenericArgument) {}
+
+    fn visit_expr_const_mut(&mut self, _: &mut syn::ExprConst) {}
+}
+
+/// Finds the first arithmetic operator that would be rewritten.
+#[derive(Default)]
+struct ArithmeticFinder {
+    found: Option<proc_macro2::Span>,
+}
+
+impl Visit<'_> for ArithmeticFinder {
+    fn visit_expr(&mut self, expr: &Expr) {
+        if self.found.is_some() {
+            return;
+        }
+
+        match expr {
+            Expr::Binary(binary)
+                if is_arithmetic(&binary.op) || is_arithmetic_assign(&binary.op) =>
+            {
+                self.found = Some(binary.op.span());
+            }
+            _ => visit::visit_expr(self, expr),
+        }
+    }
+
+    fn visit_item(&mut self, _: &syn::Item) {}
+
+    fn visit_type(&mut self, _: &Type) {}
+
+    fn visit_macro(&mut self, _: &Macro) {}
+}
+
+/// The source text of `tokens`, used in `explain!` reports.
+/// The source text of `tokens` as written in the macro input.
+///
+/// Joining spans is unstable, so the text of each token tree is concatenated
+/// with the whitespace between them, line breaks become a single space.
+/// Falls back to the stringified tokens if the source is unavailable,
+/// e.g. for tokens created by another macro.
+pub fn source(tokens: &impl ToTokens) -> String {
+    let tokens = tokens.to_token_stream();
+    let mut text = String::new();
+    let mut end: Option<LineColumn> = None;
+
+    for token in tokens.clone() {
+        let span = token.span();
+        let start = span.start();
+
+        // Line numbers start at 1, 0 means the location is unknown.
+        let Some(token_text) = span.source_text().filter(|_| start.line != 0) else {
+            return tokens.to_string();
+        };
+
+        match end {
+            Some(end) if end.line == start.line => {
+                text.extend(iter::repeat_n(' ', start.column.saturating_sub(end.column)));
+            }
+            Some(_) => text.push(' '),
+            None => {}
+        }
+
+        text.push_str(&token_text);
+        end = Some(span.end());
+    }
+
+    text
+}
+
+fn placeholder() -> Expr {
+    Expr::Verbatim(TokenStream::new())
+}
+
+/// Operators that can overflow. Bitwise operators never do and also apply to `bool`.
+fn is_arithmetic(op: &BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::Add(_)
+            | BinOp::Sub(_)
+            | BinOp::Mul(_)
+            | BinOp::Div(_)
+            | BinOp::Rem(_)
+            | BinOp::Shl(_)
+            | BinOp::Shr(_)
+    )
+}
+
+fn is_arithmetic_assign(op: &BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::AddAssign(_)
+            | BinOp::SubAssign(_)
+            | BinOp::MulAssign(_)
+            | BinOp::DivAssign(_)
+            | BinOp::RemAssign(_)
+            | BinOp::ShlAssign(_)
+            | BinOp::ShrAssign(_)
+    )
+}
+
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(_), ..
+        }) => true,
+        Expr::Paren(paren) => is_literal(&paren.expr),
+        Expr::Group(group) => is_literal(&group.expr),
+        _ => false,
+    }
+}
+
+/// Methods of builtin integers that `CheckedNum` implements as well,
+/// their receiver is made checked.
+fn is_checked_method(method: &str) -> bool {
+    matches!(method, "pow" | "abs" | "div_euclid" | "rem_euclid")
+}
+
+/// Methods of builtin integers (and `core::ops`) that perform arithmetic without checking.
+fn is_unchecked_method(method: &str) -> bool {
+    let operation = method.strip_suffix("_assign").unwrap_or(method);
+
+    matches!(
+        operation,
+        "add" | "sub" | "mul" | "div" | "rem" | "neg" | "shl" | "shr" | "sum" | "product"
+    ) || is_panicking_method(method)
+        || [
+            "unchecked_",
+            "wrapping_",
+            "overflowing_",
+            "saturating_",
+            "strict_",
+        ]
+        .iter()
+        .any(|prefix| method.starts_with(prefix))
+}
+
+/// Methods of builtin integers that overflow or panic and have no `CheckedNum` equivalent.
+fn is_panicking_method(method: &str) -> bool {
+    matches!(
+        method,
+        "next_power_of_two" | "next_multiple_of" | "div_ceil" | "isqrt"
+    ) || method.starts_with("ilog")
+}
+
+/// Calls like `u32::wrapping_add(a, b)`, `Add::add(a, b)` or `u32::pow(a, b)`,
+/// returns the name of the method.
+///
+/// The receiver of a method is only checked in method call syntax,
+/// so only the functions of `CheckedNum` types are allowed for methods it implements.
+fn unchecked_function(func: &Expr) -> Option<String> {
+    let Expr::Path(path) = func else {
+        return None;
+    };
+
+    let mut segments = path.path.segments.iter().rev();
+    let method = segments.next()?.ident.to_string();
+    let ty = segments.next()?.ident.to_string();
+
+    let unchecked =
+        is_unchecked_method(&method) || (is_checked_method(&method) && !ty.starts_with("Checked"));
+
+    unchecked.then_some(method)
+}
+
+/// How to replace an unchecked method, for error messages.
+fn replacement(method: &str) -> &'static str {
+    match method {
+        "sum" => "use `CheckedIterExt::checked_sum` instead",
+        "product" => "use `CheckedIterExt::checked_product` instead",
+        _ if is_checked_method(method) => "call it as a method, so its receiver is checked",
+        _ if is_panicking_method(method) => {
+            "use the corresponding `checked_` method of the integer instead"
+        }
+        _ => "use the corresponding operator or method of `CheckedNum` instead",
+    }
+}
+
+/// Macros whose arguments must not be rewritten.
+fn is_opaque_macro(mac: &Macro) -> bool {
+    mac.path.segments.last().is_some_and(|segment| {
+        matches!(
+            segment.ident.to_string().as_str(),
+            "checked"
+                | "explain"
+                | "stringify"
+                | "concat"
+                | "include"
+                | "include_str"
+                | "include_bytes"
+        )
+    })
+}
+
+/// Finds a binary arithmetic operator in tokens that could not be parsed as expressions.
+fn find_arithmetic_tokens(tokens: TokenStream) -> Option<proc_macro2::Span> {
+    use proc_macro2::{Spacing, TokenTree};
+
+    let mut after_operand = false;
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        after_operand = match token {
+            TokenTree::Group(group) => {
+                if let Some(span) = find_arithmetic_tokens(group.stream()) {
+                    return Some(span);
+                }
+
+                true
+            }
+            TokenTree::Literal(_) => true,
+            TokenTree::Ident(ident) => !matches!(
+                ident.to_string().as_str(),
+                "return" | "break" | "in" | "if" | "else" | "match" | "let" | "mut" | "move"
+            ),
+            TokenTree::Punct(punct) => {
+                let is_arrow = punct.as_char() == '-'
+                    && punct.spacing() == Spacing::Joint
+                    && matches!(tokens.peek(), Some(TokenTree::Punct(next)) if next.as_char() == '>');
+
+                if after_operand
+                    && !is_arrow
+                    && matches!(punct.as_char(), '+' | '-' | '*' | '/' | '%')
+                {
+                    return Some(punct.span());
+                }
+
+                false
+            }
+        };
+    }
+
+    None
+}
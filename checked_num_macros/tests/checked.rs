@@ -0,0 +1,45 @@
+// The expansion must not warn about the parentheses of the input.
+#![deny(unused_parens)]
+
+use checked_num::{CheckedI8, CheckedU32, OverflowKind, checked};
+
+#[test]
+fn precedence() {
+    let (a, b, c) = (1u8, 16, 16);
+
+    assert_eq!(checked!(a + b * c).overflow_kind(), Some(OverflowKind::Mul));
+    assert_eq!(checked!((a + b) * 2 - c), 18);
+    assert_eq!(checked!((c + b) * (c - b)), 0);
+    assert_eq!(checked!(a << 7), 128);
+    assert_eq!(
+        checked!(a << 8).overflow_kind(),
+        Some(OverflowKind::ShiftTooLarge)
+    );
+}
+
+#[test]
+fn literals() {
+    let a = -100i8;
+
+    assert_eq!(checked!(-a), 100);
+    assert_eq!(checked!(a - 28), -128);
+    assert!(checked!(a - 29).did_overflow());
+    assert!(checked!(i8: -128 * -1).did_overflow());
+    assert_eq!(checked!(a.pow(1) + 1), -99);
+    assert!(checked!(2 * a.pow(1)).did_overflow());
+}
+
+#[test]
+fn operands() {
+    let values = [u32::MAX, 1];
+    let sum = CheckedU32::new(0);
+    let half = |num: u32| num / 2;
+
+    assert!(checked!(values[0] + values[1]).did_overflow());
+    assert_eq!(checked!(sum + half(values[0]) + 1), u32::MAX / 2 + 1);
+    assert_eq!(
+        values.iter().map(|num| checked!(num / 2)).nth(1),
+        Some(CheckedU32::new(0))
+    );
+    assert_eq!(checked!(CheckedI8::new(-1) * 3), -3);
+}
@@ -0,0 +1,87 @@
+// The expansion must not warn about the parentheses of the input.
+#![deny(unused_parens)]
+
+use checked_num::{CheckedI32, CheckedI64, CheckedU16, CheckedU32, OverflowKind, checked_fn};
+
+const FEE: u32 = 5;
+
+#[checked_fn]
+fn price(qty: u32, unit: u32) -> CheckedU32 {
+    qty * unit + FEE
+}
+
+#[checked_fn]
+fn total(prices: &[u32]) -> CheckedU32 {
+    let mut total = CheckedU32::new(0);
+
+    for price in prices {
+        total += price;
+    }
+
+    if total > 100 { total - 100 } else { total * 2 }
+}
+
+// `step` is inferred from `acc`.
+#[checked_fn]
+fn scaled(base: u16) -> CheckedU16 {
+    let step = 300;
+    let mut acc = CheckedU16::new(base);
+    acc += 1;
+    acc *= step;
+    acc + (step / 100 + 1) * (step - 1)
+}
+
+// The receivers of integer methods that can overflow are checked.
+#[checked_fn]
+fn spread(a: i32, b: i32) -> CheckedI32 {
+    a.abs() + b.rem_euclid(4) + a.div_euclid(b)
+}
+
+struct Quota {
+    limit: i64,
+}
+
+#[checked_fn]
+impl Quota {
+    fn remaining(&self, used: i64) -> CheckedI64 {
+        let buffer = [0u8; 2 * 2];
+        -(used - self.limit) + buffer.len() as i64
+    }
+}
+
+#[test]
+fn checked_function() {
+    assert_eq!(price(3, 4), 17);
+    assert_eq!(price(u32::MAX, 2).overflow_kind(), Some(OverflowKind::Mul));
+}
+
+#[test]
+fn checked_control_flow() {
+    assert_eq!(total(&[10, 20]), 60);
+    assert_eq!(total(&[100, 20]), 20);
+    assert!(total(&[u32::MAX, 1]).did_overflow());
+}
+
+#[test]
+fn checked_impl_block() {
+    let quota = Quota { limit: 10 };
+
+    assert_eq!(quota.remaining(4), 10);
+    assert_eq!(
+        quota.remaining(i64::MIN).overflow_kind(),
+        Some(OverflowKind::Sub)
+    );
+}
+
+#[test]
+fn checked_untyped_operands() {
+    assert_eq!(scaled(1), 1796);
+    assert_eq!(scaled(300).overflow_kind(), Some(OverflowKind::Mul));
+}
+
+#[test]
+fn checked_methods() {
+    assert_eq!(spread(-7, 2), 5);
+    assert_eq!(spread(i32::MIN, 1).overflow_kind(), Some(OverflowKind::Neg));
+    assert_eq!(spread(1, 0).overflow_kind(), Some(OverflowKind::DivByZero));
+}
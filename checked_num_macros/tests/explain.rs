@@ -0,0 +1,56 @@
+// The expansion must not warn about the parentheses of the input.
+#![deny(unused_parens)]
+
+use checked_num::{CheckedU8, OverflowKind, explain};
+
+#[test]
+fn explain_operands() {
+    let (a, b) = (i8::MIN, 2u32);
+
+    let report = explain!(-a + 1).unwrap_err();
+    assert_eq!(report.error().kind(), OverflowKind::Neg);
+    assert_eq!(report.expr(), "-a");
+    assert!(report.operands().eq([("a", "-128")]));
+
+    let report = explain!(u32: 1 + b.pow(40)).unwrap_err();
+    assert_eq!(report.error().kind(), OverflowKind::Pow);
+    assert!(report.operands().eq([("b", "2"), ("40", "40")]));
+
+    assert_eq!(explain!(b * 3 - 1).ok(), Some(5));
+    assert_eq!(explain!((b + 1) * (b + 2).pow(2) / (b - 1)).ok(), Some(48));
+    assert_eq!(explain!(-(a + 1)).ok(), Some(127));
+}
+
+#[test]
+fn explain_failed_operand() {
+    let failed = CheckedU8::new(255) + 1;
+
+    let report = explain!(failed * 2).unwrap_err();
+    assert_eq!(report.error().kind(), OverflowKind::Add);
+    assert_eq!(report.expr(), "failed");
+    assert_eq!(report.operands().count(), 0);
+}
+
+#[test]
+fn explain_source_text() {
+    let (v, i, a) = ([3u32], 0, 2u32);
+
+    let report = explain!(v[i] * 4_000_000_000 * 2).unwrap_err();
+    assert_eq!(report.expr(), "v[i] * 4_000_000_000");
+
+    let report = explain!(a * u32::MAX).unwrap_err();
+    assert_eq!(report.expr(), "a * u32::MAX");
+    assert!(
+        report
+            .operands()
+            .eq([("a", "2"), ("u32::MAX", "4294967295")])
+    );
+
+    let report = explain!((a + 1) * u32::MAX).unwrap_err();
+    assert_eq!(report.expr(), "(a + 1) * u32::MAX");
+    assert!(
+        report
+            .operands()
+            .eq([("(a + 1)", "3"), ("u32::MAX", "4294967295")])
+    );
+}
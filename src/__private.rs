@@ -0,0 +1,172 @@
+//! Support code for the `checked_num_macros` expansions. Not public API.
+
+use core::{cell::Cell, fmt::Display, num::NonZero};
+
+use crate::{CheckedNum, OverflowReport, checked_num::CheckedNumTraits};
+
+/// Converts a macro operand into a `CheckedNum`, leaving `CheckedNum`s unchanged.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used in checked arithmetic",
+    label = "not an integer or `CheckedNum`",
+    note = "only integer arithmetic is checked, move float and string arithmetic out of \
+            `checked!` and `#[checked_fn]` bodies"
+)]
+pub trait IntoChecked {
+    type Output;
+
+    fn into_checked(self) -> Self::Output;
+}
+
+impl<T: CheckedNumTraits> IntoChecked for T {
+    type Output = CheckedNum<T>;
+
+    fn into_checked(self) -> Self::Output {
+        CheckedNum::new(self)
+    }
+}
+
+impl<T: CheckedNumTraits> IntoChecked for CheckedNum<T> {
+    type Output = Self;
+
+    fn into_checked(self) -> Self::Output {
+        self
+    }
+}
+
+impl<T: CheckedNumTraits> IntoChecked for &CheckedNum<T> {
+    type Output = CheckedNum<T>;
+
+    fn into_checked(self) -> Self::Output {
+        *self
+    }
+}
+
+// References are implemented per type,
+// a blanket impl would overlap with the one for `T: CheckedNumTraits`.
+macro_rules! impl_into_checked_ref {
+    ($($t:ty),*) => {
+        $(
+            impl IntoChecked for &$t {
+                type Output = CheckedNum<$t>;
+
+                fn into_checked(self) -> Self::Output {
+                    CheckedNum::new(*self)
+                }
+            }
+
+            impl IntoChecked for &NonZero<$t> {
+                type Output = CheckedNum<NonZero<$t>>;
+
+                fn into_checked(self) -> Self::Output {
+                    CheckedNum::new(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_checked_ref! {i128, i64, i32, i16, i8, isize, u128, u64, u32, u16, u8, usize}
+
+pub fn into_checked<T: IntoChecked>(value: T) -> T::Output {
+    value.into_checked()
+}
+
+/// An operand of an operation reported to an [`Explainer`].
+pub trait ExplainOperand {
+    type Value: Display;
+
+    /// `None` if the operand had already failed.
+    fn value(&self) -> Option<Self::Value>;
+}
+
+impl<T: CheckedNumTraits + Display> ExplainOperand for T {
+    type Value = T;
+
+    fn value(&self) -> Option<Self::Value> {
+        Some(*self)
+    }
+}
+
+impl<T: CheckedNumTraits + Display> ExplainOperand for CheckedNum<T> {
+    type Value = T;
+
+    fn value(&self) -> Option<Self::Value> {
+        self.as_option()
+    }
+}
+
+/// Records the first failing operation of an `explain!` expression.
+pub struct Explainer {
+    expr: &'static str,
+    report: Cell<Option<OverflowReport>>,
+}
+
+impl Explainer {
+    pub fn new(expr: &'static str) -> Self {
+        Self {
+            expr,
+            report: Cell::new(None),
+        }
+    }
+
+    /// A `CheckedNum` operand that may have failed before the expression.
+    pub fn leaf<T: CheckedNumTraits>(
+        &self,
+        operand: CheckedNum<T>,
+        source: &'static str,
+    ) -> CheckedNum<T> {
+        if let Err(err) = operand.into_result() {
+            self.record(|| OverflowReport::new(err, source));
+        }
+
+        operand
+    }
+
+    pub fn unary<T: CheckedNumTraits, O: ExplainOperand>(
+        &self,
+        result: CheckedNum<T>,
+        operand: &O,
+        [source, operand_source]: [&'static str; 2],
+    ) -> CheckedNum<T> {
+        if let (Err(err), Some(value)) = (result.into_result(), operand.value()) {
+            self.record(|| OverflowReport::new(err, source).with_operand(operand_source, value));
+        }
+
+        result
+    }
+
+    pub fn binary<T: CheckedNumTraits, L: ExplainOperand, R: ExplainOperand>(
+        &self,
+        result: CheckedNum<T>,
+        lhs: &L,
+        rhs: &R,
+        [source, lhs_source, rhs_source]: [&'static str; 3],
+    ) -> CheckedNum<T> {
+        if let (Err(err), Some(lhs), Some(rhs)) = (result.into_result(), lhs.value(), rhs.value()) {
+            self.record(|| {
+                OverflowReport::new(err, source)
+                    .with_operand(lhs_source, lhs)
+                    .with_operand(rhs_source, rhs)
+            });
+        }
+
+        result
+    }
+
+    // The report is stored inline so it works without an allocator.
+    #[allow(clippy::result_large_err)]
+    pub fn finish<T: CheckedNumTraits>(&self, result: CheckedNum<T>) -> Result<T, OverflowReport> {
+        result.into_result().map_err(|err| {
+            self.report
+                .get()
+                .unwrap_or(OverflowReport::new(err, self.expr))
+        })
+    }
+
+    /// Keeps only the first report, later failures are caused by it.
+    fn record(&self, report: impl FnOnce() -> OverflowReport) {
+        if self.report.get().is_none() {
+            self.report.set(Some(report()));
+        }
+    }
+}
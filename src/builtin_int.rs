@@ -1,31 +1,107 @@
 use core::num::NonZero;
 
+use num_traits::{
+    CheckedEuclid, CheckedNeg, CheckedRem, CheckedShl, CheckedShr, FromPrimitive, PrimInt,
+};
+
 /// All built-in Integer types
 ///
 /// Excludes `Wrapping<_>``
-pub trait BuiltinInt: Copy {}
-
-impl BuiltinInt for i128 {}
-impl BuiltinInt for i64 {}
-impl BuiltinInt for i32 {}
-impl BuiltinInt for i16 {}
-impl BuiltinInt for i8 {}
-impl BuiltinInt for u128 {}
-impl BuiltinInt for u64 {}
-impl BuiltinInt for u32 {}
-impl BuiltinInt for u16 {}
-impl BuiltinInt for u8 {}
-
-impl BuiltinInt for NonZero<i128> {}
-impl BuiltinInt for NonZero<i64> {}
-impl BuiltinInt for NonZero<i32> {}
-impl BuiltinInt for NonZero<i16> {}
-impl BuiltinInt for NonZero<i8> {}
-impl BuiltinInt for NonZero<u128> {}
-impl BuiltinInt for NonZero<u64> {}
-impl BuiltinInt for NonZero<u32> {}
-impl BuiltinInt for NonZero<u16> {}
-impl BuiltinInt for NonZero<u8> {}
+pub trait BuiltinInt: Copy {
+    /// The primitive integer type with the same layout.
+    ///
+    /// `Self` for primitive integers, `T` for `NonZero<T>`.
+    /// All checked arithmetic is performed on this type.
+    type Primitive: BuiltinInt<Primitive = Self::Primitive>
+        + PrimInt
+        + FromPrimitive
+        + CheckedNeg
+        + CheckedRem
+        + CheckedShl
+        + CheckedShr
+        + CheckedEuclid;
+
+    /// The unsigned integer type of the same size.
+    ///
+    /// `Self` for unsigned types, `NonZero<_>` for `NonZero<_>` types.
+    type Unsigned: BuiltinInt;
+
+    const MIN: Self;
+    const MAX: Self;
+
+    fn into_primitive(self) -> Self::Primitive;
+
+    /// Returns `None` if `num` is not a valid value of `Self` (zero for `NonZero<_>`).
+    fn from_primitive(num: Self::Primitive) -> Option<Self>;
+
+    // Operations of the primitive types without a `num_traits` equivalent.
+
+    fn abs_diff(
+        lhs: Self::Primitive,
+        rhs: Self::Primitive,
+    ) -> <Self::Unsigned as BuiltinInt>::Primitive;
+
+    fn midpoint(lhs: Self::Primitive, rhs: Self::Primitive) -> Self::Primitive;
+}
+
+macro_rules! impl_builtin_int {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(
+            impl BuiltinInt for $t {
+                type Primitive = $t;
+                type Unsigned = $unsigned;
+
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn into_primitive(self) -> Self::Primitive {
+                    self
+                }
+
+                fn from_primitive(num: Self::Primitive) -> Option<Self> {
+                    Some(num)
+                }
+
+                fn abs_diff(lhs: $t, rhs: $t) -> $unsigned {
+                    lhs.abs_diff(rhs)
+                }
+
+                fn midpoint(lhs: $t, rhs: $t) -> $t {
+                    lhs.midpoint(rhs)
+                }
+            }
+
+            impl BuiltinInt for NonZero<$t> {
+                type Primitive = $t;
+                type Unsigned = NonZero<$unsigned>;
+
+                const MIN: Self = NonZero::<$t>::MIN;
+                const MAX: Self = NonZero::<$t>::MAX;
+
+                fn into_primitive(self) -> Self::Primitive {
+                    self.get()
+                }
+
+                fn from_primitive(num: Self::Primitive) -> Option<Self> {
+                    NonZero::new(num)
+                }
+
+                fn abs_diff(lhs: $t, rhs: $t) -> $unsigned {
+                    lhs.abs_diff(rhs)
+                }
+
+                fn midpoint(lhs: $t, rhs: $t) -> $t {
+                    lhs.midpoint(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_builtin_int! {
+    i128 => u128, i64 => u64, i32 => u32, i16 => u16, i8 => u8, isize => usize,
+    u128 => u128, u64 => u64, u32 => u32, u16 => u16, u8 => u8, usize => usize
+}
 
 // Wrapping<T> is purposfully ignored!
 // Adding checked to wrapping values does not make sense.
@@ -1,20 +1,35 @@
 use core::{
     cmp::Ordering,
     fmt::Debug,
-    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Shl, Shr, Sub},
+    num::NonZero,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+        SubAssign,
+    },
 };
 
-use num_traits::Inv;
+#[cfg(feature = "location")]
+use core::panic::Location;
+
 use num_traits::ops::checked::*;
+use num_traits::{
+    Bounded, CheckedEuclid, Euclid, FromPrimitive, Inv, MulAdd, MulAddAssign, Num, NumCast, One,
+    PrimInt, ToPrimitive, Zero,
+};
 
-use crate::{CheckedU32, builtin_int::BuiltinInt};
+use crate::{
+    CheckedIsize, CheckedU32, CheckedUsize, ParseError, Promote,
+    builtin_int::BuiltinInt,
+    overflow::{OverflowDirection, OverflowError, OverflowKind},
+};
 
 /// Overflow-Checked Number.
 /// Can be used like any other integer type.
 ///
 /// # Operations with non-checked types
 /// Integer types of the same bitsize can be used in binary operations
-/// with `CheckedNum`, as long as they appear on the right-hand side.
+/// with `CheckedNum`, on either side of the operator.
 ///
 /// These calculations will behave exactly the same
 /// as when performed between two `CheckedNum` values.
@@ -26,34 +41,42 @@ use crate::{CheckedU32, builtin_int::BuiltinInt};
 /// let a = CheckedU16::new(123);
 /// let b = 210;
 ///
-/// assert_eq!(a + b, 333) // correct
+/// assert_eq!(a + b, 333);
+/// assert_eq!(b + a, 333);
+/// assert!(b < a + b);
 /// ```
 ///
-/// ```compile_fail
-/// use checked_num::CheckedU16;
+/// # Mixed widths
+/// Operands of other widths have to be marked explicitly,
+/// so the type inference of integer literals like in `num + 1` is not affected.
+/// [`Promote`](crate::Promote) performs the operation in the wider type,
+/// [`Narrow`](crate::Narrow) keeps the width of the left-hand side.
 ///
-/// let a = CheckedU16::new(123);
-/// let b = 210;
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedU8, CheckedU32, Promote};
 ///
-/// assert_eq!(b + a, 333) // fails to compile
-/// ```
+/// let a = CheckedU8::new(200);
 ///
-/// This is a rust limitation that cannot be overcome.
+/// assert_eq!(a + Promote::new(100u32), 300u32);
+/// assert_eq!(a + CheckedU32::new(100).promote(), 300u32);
+/// assert!((a + CheckedU32::new(100).narrow()).did_overflow());
+/// ```
 ///
 /// # Overflow
-/// In case of an overflow the value is discarded.
+/// In case of an overflow the value is discarded and the reason is recorded as an [`OverflowError`].
 /// The error will be propagated in all subsequent calculations (similar to NaN in floats).
 ///
 /// Example:
 /// ```rust
-/// use checked_num::CheckedI8;
+/// use checked_num::{CheckedI8, OverflowKind};
 ///
 /// let a = CheckedI8::new(100);
 /// let b = CheckedI8::new(100);
 /// let c = CheckedI8::new(100);
 ///
 /// assert!((a + b - c).did_overflow());
-///
+/// assert_eq!((a + b - c).overflow_kind(), Some(OverflowKind::Add));
 /// ```
 ///
 /// With overflowing behavior this would instead correctly result in 100
@@ -70,54 +93,915 @@ use crate::{CheckedU32, builtin_int::BuiltinInt};
 ///
 /// assert_ne!(a + b, a + b);
 /// ```
+///
+/// # Memory layout
+/// The recorded [`OverflowError`] takes space next to the value,
+/// so `CheckedNum<T>` is twice the size of `T`, including `NonZero<_>` types.
+/// With the `location` feature it additionally stores a pointer.
+///
+/// Example:
+/// ```rust
+/// # #[cfg(not(feature = "location"))]
+/// # {
+/// use checked_num::{CheckedNonZeroU8, CheckedNonZeroU32, CheckedNonZeroU64};
+/// use core::mem::size_of;
+///
+/// assert_eq!(size_of::<CheckedNonZeroU8>(), 2);
+/// assert_eq!(size_of::<CheckedNonZeroU32>(), 8);
+/// assert_eq!(size_of::<CheckedNonZeroU64>(), 16);
+/// # }
+/// ```
 #[must_use]
 #[derive(Debug, Clone, Copy)]
-pub struct CheckedNum<T: CheckedNumTraits>(Option<T>);
+pub struct CheckedNum<T: CheckedNumTraits>(Result<T, OverflowError>);
 
 // This bound is purposfully restrictive to avoid breaking changes
 pub trait CheckedNumTraits: BuiltinInt {}
 impl<T: BuiltinInt> CheckedNumTraits for T {}
 
 impl<T: CheckedNumTraits> CheckedNum<T> {
-    const OVERFLOWED: Self = Self(None);
-
     pub fn new(num: T) -> Self {
-        Self(Some(num))
+        Self(Ok(num))
+    }
+
+    /// Creates an already failed value.
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn overflowed<E: Into<OverflowError>>(err: E) -> Self {
+        Self(Err(err.into()))
     }
 
     pub fn as_option(self) -> Option<T> {
-        self.0
+        self.0.ok()
     }
 
     pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        self.0.or(Err(err))
+    }
+
+    /// Returns the value, or the [`OverflowError`] describing why the calculation failed.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedI32, OverflowKind};
+    ///
+    /// let result = (CheckedI32::new(i32::MIN) / -1).into_result();
+    ///
+    /// assert_eq!(result.unwrap_err().kind(), OverflowKind::Div);
+    /// ```
+    pub fn into_result(self) -> Result<T, OverflowError> {
+        self.0
+    }
+
+    pub fn did_overflow(&self) -> bool {
+        self.0.is_err()
+    }
+
+    /// Returns the kind of the first failed operation, or `None` if no operation failed.
+    pub fn overflow_kind(&self) -> Option<OverflowKind> {
+        self.0.err().map(|err| err.kind())
+    }
+
+    /// Returns the source location of the first failed operation,
+    /// or `None` if no operation failed.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU8;
+    ///
+    /// let a = CheckedU8::new(200);
+    /// let b = (a + 100) * 2;
+    ///
+    /// assert_eq!(b.overflow_location().unwrap().line(), line!() - 2);
+    /// ```
+    #[cfg(feature = "location")]
+    pub fn overflow_location(&self) -> Option<&'static Location<'static>> {
+        self.0.err().map(|err| err.location())
+    }
+
+    /// Returns the value, or the bound of `T` in the direction of the overflow.
+    ///
+    /// Returns `None` if the direction of the overflow is [`OverflowDirection::Undefined`],
+    /// e.g. for a division by zero.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedI8;
+    ///
+    /// assert_eq!((CheckedI8::new(100) * 2).saturate(), Some(i8::MAX));
+    /// assert_eq!((CheckedI8::new(100) * -2).saturate(), Some(i8::MIN));
+    /// assert_eq!((CheckedI8::new(100) / 0).saturate(), None);
+    /// ```
+    pub fn saturate(self) -> Option<T> {
         match self.0 {
-            Some(v) => Ok(v),
-            None => Err(err),
+            Ok(num) => Some(num),
+            Err(err) => match err.direction() {
+                OverflowDirection::Positive => Some(T::MAX),
+                OverflowDirection::Negative => Some(T::MIN),
+                OverflowDirection::Undefined => None,
+            },
         }
     }
 
-    pub fn did_overflow(&self) -> bool {
-        self.as_option().is_none()
+    /// Like [`CheckedNum::saturate`], but returns `default` if the direction is undefined.
+    pub fn saturate_or(self, default: T) -> T {
+        self.saturate().unwrap_or(default)
+    }
+
+    /// Applies `f` to the value, keeping a failure unchanged.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU8;
+    ///
+    /// let doubled = CheckedU8::new(100).map(|num| u16::from(num) * 2);
+    ///
+    /// assert_eq!(doubled, 200u16);
+    /// ```
+    pub fn map<U: CheckedNumTraits>(self, f: impl FnOnce(T) -> U) -> CheckedNum<U> {
+        CheckedNum(self.0.map(f))
+    }
+
+    /// Applies a checked calculation to the value, keeping a failure unchanged.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU8;
+    ///
+    /// let result = CheckedU8::new(100).and_then(|num| CheckedU8::new(num) * 3);
+    ///
+    /// assert!(result.did_overflow());
+    /// ```
+    pub fn and_then<U: CheckedNumTraits>(
+        self,
+        f: impl FnOnce(T) -> CheckedNum<U>,
+    ) -> CheckedNum<U> {
+        CheckedNum(self.0.and_then(|num| f(num).0))
+    }
+
+    pub fn unwrap_or(self, default: T) -> T {
+        self.0.unwrap_or(default)
+    }
+
+    pub fn unwrap_or_else(self, f: impl FnOnce(OverflowError) -> T) -> T {
+        self.0.unwrap_or_else(f)
+    }
+
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.0.unwrap_or_default()
+    }
+
+    /// Returns the value.
+    ///
+    /// # Panics
+    /// Panics with `msg` and the [`OverflowError`] if the calculation failed.
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T {
+        match self.0 {
+            Ok(num) => num,
+            Err(err) => panic!("{msg}: {err}"),
+        }
+    }
+
+    /// Returns the value.
+    ///
+    /// # Panics
+    /// Panics with the [`OverflowError`] if the calculation failed.
+    #[track_caller]
+    pub fn unwrap(self) -> T {
+        match self.0 {
+            Ok(num) => num,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Returns `self` if it did not fail, otherwise `other`.
+    pub fn or(self, other: Self) -> Self {
+        match self.0 {
+            Ok(_) => self,
+            Err(_) => other,
+        }
+    }
+
+    /// Returns `self` if it did not fail, otherwise the result of `f`.
+    pub fn or_else(self, f: impl FnOnce(OverflowError) -> Self) -> Self {
+        match self.0 {
+            Ok(_) => self,
+            Err(err) => f(err),
+        }
+    }
+
+    /// Fails with [`OverflowKind::Filtered`] if `predicate` returns `false`.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedI32, OverflowKind};
+    ///
+    /// let even = |num: &i32| num % 2 == 0;
+    ///
+    /// assert_eq!(CheckedI32::new(4).filter(even), 4);
+    /// assert_eq!(
+    ///     CheckedI32::new(5).filter(even).overflow_kind(),
+    ///     Some(OverflowKind::Filtered)
+    /// );
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn filter(self, predicate: impl FnOnce(&T) -> bool) -> Self {
+        match self.0 {
+            Ok(num) if !predicate(&num) => Self::overflowed(OverflowKind::Filtered),
+            _ => self,
+        }
+    }
+
+    /// Combines two values, returning the first failure.
+    pub fn zip<U: CheckedNumTraits>(self, other: CheckedNum<U>) -> Result<(T, U), OverflowError> {
+        Ok((self.0?, other.0?))
+    }
+
+    /// Calls `f` with the value if the calculation did not fail.
+    pub fn inspect(self, f: impl FnOnce(&T)) -> Self {
+        if let Ok(num) = &self.0 {
+            f(num);
+        }
+
+        self
+    }
+
+    /// Calls `f` with the error if the calculation failed.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU8;
+    ///
+    /// let mut failures = 0;
+    /// let result = (CheckedU8::new(255) + 1).inspect_overflow(|_| failures += 1);
+    ///
+    /// assert!(result.did_overflow());
+    /// assert_eq!(failures, 1);
+    /// ```
+    pub fn inspect_overflow(self, f: impl FnOnce(&OverflowError)) -> Self {
+        if let Err(err) = &self.0 {
+            f(err);
+        }
+
+        self
+    }
+
+    /// Returns `true` if the calculation did not fail and the value matches `f`.
+    pub fn is_ok_and(self, f: impl FnOnce(T) -> bool) -> bool {
+        self.0.is_ok_and(f)
+    }
+
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// `exp` can be a `u32` or a `CheckedU32`, whose failure is propagated.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedU8, CheckedU32, CheckedU64};
+    ///
+    /// assert_eq!(CheckedU8::new(3).pow(5), 243);
+    /// assert!(CheckedU8::new(3).pow(6).did_overflow());
+    ///
+    /// let digits = CheckedU32::new(19) + 1;
+    /// assert!(CheckedU64::new(10).pow(digits).did_overflow());
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn pow<B: Into<CheckedU32>>(self, exp: B) -> Self {
+        let exp = match exp.into().0 {
+            Ok(exp) => exp,
+            Err(err) => return self.fail_with(err),
+        };
+
+        self.map_primitive(|mut base| {
+            let direction = match base < Zero::zero() && exp & 1 == 1 {
+                true => OverflowDirection::Negative,
+                false => OverflowDirection::Positive,
+            };
+            let err = OverflowError::new(OverflowKind::Pow, direction);
+
+            let mut exp = exp;
+            let mut acc = T::Primitive::one();
+
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    acc = acc.checked_mul(&base).ok_or(err)?;
+                }
+
+                exp >>= 1;
+
+                if exp > 0 {
+                    base = base.checked_mul(&base).ok_or(err)?;
+                }
+            }
+
+            Ok(acc)
+        })
+    }
+
+    /// Euclidean division, rounding the quotient so that the remainder is non-negative.
+    ///
+    /// Fails on division by zero and on `MIN / -1`.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedI32;
+    ///
+    /// assert_eq!(CheckedI32::new(-7).div_euclid(2), -4);
+    /// assert!(CheckedI32::new(i32::MIN).div_euclid(-1).did_overflow());
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn div_euclid<B: Into<Self>>(self, rhs: B) -> Self {
+        self.euclid_op(
+            rhs.into(),
+            OverflowKind::Div,
+            CheckedEuclid::checked_div_euclid,
+        )
+    }
+
+    /// The least non-negative remainder of the euclidean division.
+    ///
+    /// Fails on division by zero and on `MIN % -1`.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedI32;
+    ///
+    /// assert_eq!(CheckedI32::new(-7).rem_euclid(2), 1);
+    /// assert!(CheckedI32::new(-7).rem_euclid(0).did_overflow());
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn rem_euclid<B: Into<Self>>(self, rhs: B) -> Self {
+        self.euclid_op(
+            rhs.into(),
+            OverflowKind::Rem,
+            CheckedEuclid::checked_rem_euclid,
+        )
+    }
+
+    /// The smaller of two values, failing if either has failed.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU8;
+    ///
+    /// assert_eq!(CheckedU8::new(3).min(5), 3);
+    /// assert!(CheckedU8::new(3).min(CheckedU8::new(255) + 1).did_overflow());
+    /// ```
+    pub fn min<B: Into<Self>>(self, rhs: B) -> Self {
+        self.select(rhs.into(), |num, rhs| num <= rhs)
+    }
+
+    /// The larger of two values, failing if either has failed.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU8;
+    ///
+    /// assert_eq!(CheckedU8::new(3).max(5), 5);
+    /// assert!((CheckedU8::new(255) + 1).max(5).did_overflow());
+    /// ```
+    pub fn max<B: Into<Self>>(self, rhs: B) -> Self {
+        self.select(rhs.into(), |num, rhs| num >= rhs)
+    }
+
+    /// Restricts the value to the range `lo..=hi`, failing if any of them has failed.
+    ///
+    /// # Panics
+    /// Panics if `lo > hi`, like `Ord::clamp`.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU32;
+    ///
+    /// assert_eq!(CheckedU32::new(120).clamp(10, 100), 100);
+    /// assert_eq!(CheckedU32::new(5).clamp(10, 100), 10);
+    /// assert!((CheckedU32::new(u32::MAX) + 1).clamp(10, 100).did_overflow());
+    /// ```
+    #[track_caller]
+    pub fn clamp<L: Into<Self>, H: Into<Self>>(self, lo: L, hi: H) -> Self {
+        let (lo, hi) = (lo.into(), hi.into());
+
+        if let (Ok(lo), Ok(hi)) = (lo.0, hi.0) {
+            assert!(
+                lo.into_primitive() <= hi.into_primitive(),
+                "clamp requires lo <= hi"
+            );
+        }
+
+        self.max(lo).min(hi)
+    }
+
+    /// The absolute difference as the unsigned type of the same size.
+    ///
+    /// Never fails for valid values, as the difference always fits.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedI8;
+    ///
+    /// assert_eq!(CheckedI8::new(-128).abs_diff(127), 255u8);
+    /// assert!((CheckedI8::new(127) + 1).abs_diff(0).did_overflow());
+    /// ```
+    pub fn abs_diff<B: Into<Self>>(
+        self,
+        rhs: B,
+    ) -> CheckedNum<<T::Primitive as BuiltinInt>::Unsigned> {
+        match rhs.into().0 {
+            Ok(rhs_num) => {
+                let rhs_num = rhs_num.into_primitive();
+                self.map_primitive(|num| Ok(T::Primitive::abs_diff(num, rhs_num)))
+            }
+            Err(err) => self.fail_with(err),
+        }
+    }
+
+    /// The middle of two values, rounded towards zero like the primitive `midpoint`.
+    ///
+    /// Only fails if an input has failed, or for `NonZero<_>` types if the result is zero.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedI32, CheckedU8};
+    ///
+    /// assert_eq!(CheckedU8::new(255).midpoint(254), 254);
+    /// assert_eq!(CheckedI32::new(-3).midpoint(0), -1);
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn midpoint<B: Into<Self>>(self, rhs: B) -> Self {
+        match rhs.into().0 {
+            Ok(rhs_num) => {
+                let rhs_num = rhs_num.into_primitive();
+                self.map_primitive(|num| Ok(T::Primitive::midpoint(num, rhs_num)))
+            }
+            Err(err) => self.fail_with(err),
+        }
+    }
+
+    /// The absolute value, failing on `MIN` of signed types.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedI8, OverflowKind};
+    ///
+    /// assert_eq!(CheckedI8::new(-5).abs(), 5);
+    /// assert_eq!(CheckedI8::new(-128).abs().overflow_kind(), Some(OverflowKind::Neg));
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn abs(self) -> Self {
+        match self.is_negative() {
+            true => -self,
+            false => self,
+        }
+    }
+
+    /// `-1`, `0` or `1` depending on the sign.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedI32;
+    ///
+    /// assert_eq!(CheckedI32::new(-20).signum(), -1);
+    /// assert_eq!(CheckedI32::new(0).signum(), 0);
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn signum(self) -> Self {
+        self.map_primitive(|num| {
+            let (zero, one) = (T::Primitive::zero(), T::Primitive::one());
+
+            Ok(match num.cmp(&zero) {
+                // only reachable for signed types
+                Ordering::Less => zero - one,
+                Ordering::Equal => zero,
+                Ordering::Greater => one,
+            })
+        })
+    }
+
+    /// Whether the value is less than zero, `false` for failed values.
+    pub fn is_negative(&self) -> bool {
+        self.0
+            .as_ref()
+            .is_ok_and(|num| num.into_primitive() < Zero::zero())
+    }
+
+    /// Whether the value is greater than zero, `false` for failed values.
+    pub fn is_positive(&self) -> bool {
+        self.0
+            .as_ref()
+            .is_ok_and(|num| num.into_primitive() > Zero::zero())
+    }
+
+    /// The absolute value as the unsigned type of the same size, which never fails.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedI8;
+    ///
+    /// assert_eq!(CheckedI8::new(-128).unsigned_abs(), 128u8);
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn unsigned_abs(self) -> CheckedNum<T::Unsigned> {
+        self.map_primitive(|num| Ok(T::abs_diff(num, Zero::zero())))
+    }
+
+    /// Shifts the bits to the left by `rhs`, discarding bits shifted out of the value.
+    ///
+    /// Only fails if `rhs` is greater or equal to the bit width of `T`.
+    /// Use `<<` to also detect lost bits.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU8;
+    ///
+    /// assert_eq!(CheckedU8::new(255).shl_bits(1), 254);
+    /// assert!((CheckedU8::new(255) << 1).did_overflow());
+    /// assert!(CheckedU8::new(255).shl_bits(8).did_overflow());
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn shl_bits<B: Into<CheckedU32>>(self, rhs: B) -> Self {
+        match rhs.into().0 {
+            Ok(rhs_num) => self.map_primitive(|num| {
+                num.checked_shl(rhs_num)
+                    .ok_or(OverflowKind::ShiftTooLarge.into())
+            }),
+            Err(err) => self.fail_with(err),
+        }
+    }
+
+    /// Converts to `usize`, failing if the value does not fit into the platform's pointer width.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedI32, CheckedU128};
+    ///
+    /// assert_eq!(CheckedI32::new(42).checked_to_usize(), 42);
+    /// assert!(CheckedI32::new(-1).checked_to_usize().did_overflow());
+    /// assert!(CheckedU128::new(u128::MAX).checked_to_usize().did_overflow());
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn checked_to_usize(self) -> CheckedUsize
+    where
+        usize: TryFrom<T::Primitive>,
+    {
+        self.convert()
+    }
+
+    /// Converts to `isize`, failing if the value does not fit into the platform's pointer width.
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn checked_to_isize(self) -> CheckedIsize
+    where
+        isize: TryFrom<T::Primitive>,
+    {
+        self.convert()
+    }
+
+    /// Converts from `usize`, failing if the value does not fit into `T`.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedU8, CheckedU64};
+    ///
+    /// let len: usize = 300;
+    ///
+    /// assert_eq!(CheckedU64::checked_from_usize(len), 300);
+    /// assert!(CheckedU8::checked_from_usize(len).did_overflow());
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn checked_from_usize<B: Into<CheckedUsize>>(num: B) -> Self
+    where
+        T::Primitive: TryFrom<usize>,
+    {
+        num.into().convert()
+    }
+
+    /// Converts from `isize`, failing if the value does not fit into `T`.
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn checked_from_isize<B: Into<CheckedIsize>>(num: B) -> Self
+    where
+        T::Primitive: TryFrom<isize>,
+    {
+        num.into().convert()
+    }
+
+    /// Converts into any other `CheckedNum`, failing if the value does not fit into `U`.
+    ///
+    /// Unlike `as`, out of range values are not truncated.
+    /// For `NonZero<_>` targets zero fails with [`OverflowKind::Zero`].
+    ///
+    /// Lossless conversions are also available through `From`,
+    /// fallible ones through `TryFrom`.
+    ///
+    /// ```rust
+    /// use core::num::NonZero;
+    ///
+    /// use checked_num::{CheckedI32, CheckedU16, OverflowKind};
+    ///
+    /// assert_eq!(CheckedU16::new(300).cast::<i64>(), 300);
+    /// assert!(CheckedU16::new(300).cast::<u8>().did_overflow());
+    /// assert!(CheckedI32::new(-1).cast::<u32>().did_overflow());
+    ///
+    /// assert_eq!(
+    ///     CheckedU16::new(0).cast::<NonZero<u8>>().overflow_kind(),
+    ///     Some(OverflowKind::Zero)
+    /// );
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn cast<U: CheckedNumTraits>(self) -> CheckedNum<U>
+    where
+        U::Primitive: TryFrom<T::Primitive>,
+    {
+        self.convert()
+    }
+
+    /// Converts into another `CheckedNum` through the `TryFrom` impls of the underlying primitives.
+    #[cfg_attr(feature = "location", track_caller)]
+    fn convert<U: CheckedNumTraits>(self) -> CheckedNum<U>
+    where
+        U::Primitive: TryFrom<T::Primitive>,
+    {
+        self.map_primitive(|num| {
+            U::Primitive::try_from(num)
+                .map_err(|_| OverflowError::new(OverflowKind::Conversion, sign_direction(num)))
+        })
+    }
+
+    /// Applies a checked operation to the underlying primitive.
+    ///
+    /// For `NonZero<_>` types a result of zero fails with [`OverflowKind::Zero`].
+    #[cfg_attr(feature = "location", track_caller)]
+    pub(crate) fn map_primitive<U: CheckedNumTraits>(
+        self,
+        op: impl FnOnce(T::Primitive) -> Result<U::Primitive, OverflowError>,
+    ) -> CheckedNum<U> {
+        let num = match self.0 {
+            Ok(num) => num.into_primitive(),
+            Err(err) => return CheckedNum(Err(err)),
+        };
+
+        CheckedNum::from_primitive_result(op(num))
+    }
+
+    /// Creates a value from the result of a checked operation on the underlying primitive.
+    ///
+    /// For `NonZero<_>` types a result of zero fails with [`OverflowKind::Zero`].
+    #[cfg_attr(feature = "location", track_caller)]
+    pub(crate) fn from_primitive_result(result: Result<T::Primitive, OverflowError>) -> Self {
+        match result.and_then(|num| T::from_primitive(num).ok_or(OverflowKind::Zero.into())) {
+            Ok(num) => Self::new(num),
+            // The error may come from a closure, which can not track the caller
+            Err(err) => Self(Err(err.at_caller())),
+        }
+    }
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn euclid_op(
+        self,
+        rhs: Self,
+        kind: OverflowKind,
+        op: impl FnOnce(&T::Primitive, &T::Primitive) -> Option<T::Primitive>,
+    ) -> Self {
+        match rhs.0 {
+            Ok(rhs_num) => {
+                let rhs_num = rhs_num.into_primitive();
+                self.map_primitive(|num| {
+                    op(&num, &rhs_num).ok_or_else(|| binary_op_failure(kind, num, rhs_num))
+                })
+            }
+            Err(err) => self.fail_with(err),
+        }
+    }
+
+    /// Picks `self` if `pick_self` returns `true`, otherwise `rhs`.
+    ///
+    /// Fails with the first error of `self` and `rhs`.
+    fn select(self, rhs: Self, pick_self: impl FnOnce(T::Primitive, T::Primitive) -> bool) -> Self {
+        match (self.0, rhs.0) {
+            (Ok(num), Ok(rhs_num)) => {
+                match pick_self(num.into_primitive(), rhs_num.into_primitive()) {
+                    true => self,
+                    false => rhs,
+                }
+            }
+            (Ok(_), Err(err)) => self.fail_with(err),
+            (Err(_), _) => self,
+        }
+    }
+
+    /// Fails with the error of `self` if it already failed, otherwise with `err`.
+    ///
+    /// Used to propagate the first failure of a binary operation.
+    fn fail_with<U: CheckedNumTraits>(self, err: OverflowError) -> CheckedNum<U> {
+        CheckedNum(Err(self.0.err().unwrap_or(err)))
+    }
+}
+
+/// Describes the failure of the binary operation `lhs <kind> rhs`.
+fn binary_op_failure<P: PrimInt>(kind: OverflowKind, lhs: P, rhs: P) -> OverflowError {
+    let is_negative = |num: P| num < P::zero();
+
+    let (kind, direction) = match kind {
+        OverflowKind::Div | OverflowKind::Rem if rhs.is_zero() => {
+            (OverflowKind::DivByZero, OverflowDirection::Undefined)
+        }
+        // `MIN % -1` is zero, which is in range but still fails
+        OverflowKind::Rem => (kind, OverflowDirection::Undefined),
+        OverflowKind::Add if is_negative(rhs) => (kind, OverflowDirection::Negative),
+        OverflowKind::Sub if !is_negative(rhs) => (kind, OverflowDirection::Negative),
+        OverflowKind::Mul if is_negative(lhs) != is_negative(rhs) => {
+            (kind, OverflowDirection::Negative)
+        }
+        _ => (kind, OverflowDirection::Positive),
+    };
+
+    OverflowError::new(kind, direction)
+}
+
+/// The direction in which a value too large in magnitude overflows.
+fn sign_direction<P: PrimInt>(num: P) -> OverflowDirection {
+    match num < P::zero() {
+        true => OverflowDirection::Negative,
+        false => OverflowDirection::Positive,
     }
 }
 
 impl<T: CheckedNumTraits> From<T> for CheckedNum<T> {
     fn from(value: T) -> Self {
-        Self(Some(value))
+        Self(Ok(value))
     }
 }
 
 impl<T: CheckedNumTraits> From<Option<T>> for CheckedNum<T> {
+    #[cfg_attr(feature = "location", track_caller)]
     fn from(maybe_num: Option<T>) -> Self {
-        CheckedNum(maybe_num)
+        maybe_num.map_or(Self::overflowed(OverflowKind::Unknown), Self::new)
+    }
+}
+
+impl<T: CheckedNumTraits> From<Result<T, OverflowError>> for CheckedNum<T> {
+    fn from(result: Result<T, OverflowError>) -> Self {
+        CheckedNum(result)
     }
 }
 
+// `Promote` operands of a different width are converted into the wider of both types.
+macro_rules! impl_promoting_ops {
+    ($narrow:ty => $wide:ty, $($trait:ident, $trait_fn:ident, $assign_trait:ident, $assign_fn:ident),*) => {
+        $(
+            impl $trait<Promote<$wide>> for CheckedNum<$narrow> {
+                type Output = CheckedNum<$wide>;
+
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $trait_fn(self, rhs: Promote<$wide>) -> Self::Output {
+                    <CheckedNum<$wide> as From<_>>::from(self).$trait_fn(rhs.0)
+                }
+            }
+
+            impl $trait<Promote<$narrow>> for CheckedNum<$wide> {
+                type Output = Self;
+
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $trait_fn(self, rhs: Promote<$narrow>) -> Self::Output {
+                    self.$trait_fn(<CheckedNum<$wide> as From<_>>::from(rhs.0))
+                }
+            }
+
+            impl $assign_trait<Promote<$narrow>> for CheckedNum<$wide> {
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $assign_fn(&mut self, rhs: Promote<$narrow>) {
+                    *self = self.$trait_fn(rhs);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_casts {
+    ($($from:ty => [$($wide:ty),*], [$($narrow:ty),*];)*) => {
+        $(
+            $(
+                impl From<CheckedNum<$from>> for CheckedNum<$wide> {
+                    fn from(num: CheckedNum<$from>) -> Self {
+                        CheckedNum(num.0.map(<$wide as From<_>>::from))
+                    }
+                }
+
+                impl From<CheckedNum<NonZero<$from>>> for CheckedNum<NonZero<$wide>> {
+                    fn from(num: CheckedNum<NonZero<$from>>) -> Self {
+                        CheckedNum(num.0.map(NonZero::<$wide>::from))
+                    }
+                }
+
+                impl From<CheckedNum<NonZero<$from>>> for CheckedNum<$wide> {
+                    fn from(num: CheckedNum<NonZero<$from>>) -> Self {
+                        CheckedNum(num.0.map(|num| <$wide as From<_>>::from(num.get())))
+                    }
+                }
+
+                impl_promoting_ops! {$from => $wide, Add, add, AddAssign, add_assign, Sub, sub, SubAssign, sub_assign, Mul, mul, MulAssign, mul_assign, Div, div, DivAssign, div_assign, Rem, rem, RemAssign, rem_assign}
+            )*
+
+            $(
+                impl TryFrom<CheckedNum<$from>> for CheckedNum<$narrow> {
+                    type Error = OverflowError;
+
+                    #[cfg_attr(feature = "location", track_caller)]
+                    fn try_from(num: CheckedNum<$from>) -> Result<Self, Self::Error> {
+                        num.cast().into_result().map(Self::new)
+                    }
+                }
+
+                impl TryFrom<CheckedNum<NonZero<$from>>> for CheckedNum<NonZero<$narrow>> {
+                    type Error = OverflowError;
+
+                    #[cfg_attr(feature = "location", track_caller)]
+                    fn try_from(num: CheckedNum<NonZero<$from>>) -> Result<Self, Self::Error> {
+                        num.cast().into_result().map(Self::new)
+                    }
+                }
+            )*
+
+            impl From<CheckedNum<NonZero<$from>>> for CheckedNum<$from> {
+                fn from(num: CheckedNum<NonZero<$from>>) -> Self {
+                    CheckedNum(num.0.map(NonZero::get))
+                }
+            }
+
+            impl TryFrom<CheckedNum<$from>> for CheckedNum<NonZero<$from>> {
+                type Error = OverflowError;
+
+                #[cfg_attr(feature = "location", track_caller)]
+                fn try_from(num: CheckedNum<$from>) -> Result<Self, Self::Error> {
+                    num.cast().into_result().map(Self::new)
+                }
+            }
+        )*
+    };
+}
+
+// Widening follows the lossless `From` impls of the primitives,
+// narrowing covers all remaining pairs.
+impl_casts! {
+    u8 => [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize], [i8];
+    u16 => [u32, u64, u128, usize, i32, i64, i128], [u8, i8, i16, isize];
+    u32 => [u64, u128, i64, i128], [u8, u16, usize, i8, i16, i32, isize];
+    u64 => [u128, i128], [u8, u16, u32, usize, i8, i16, i32, i64, isize];
+    u128 => [], [u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize];
+    usize => [], [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize];
+    i8 => [i16, i32, i64, i128, isize], [u8, u16, u32, u64, u128, usize];
+    i16 => [i32, i64, i128, isize], [u8, u16, u32, u64, u128, usize, i8];
+    i32 => [i64, i128], [u8, u16, u32, u64, u128, usize, i8, i16, isize];
+    i64 => [i128], [u8, u16, u32, u64, u128, usize, i8, i16, i32, isize];
+    i128 => [], [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize];
+    isize => [], [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128];
+}
+
+// Like the primitive methods, the mixed-sign operations only exist
+// for the signedness where they are meaningful.
+macro_rules! impl_mixed_sign_ops {
+    ($($signed:ty => $unsigned:ty),*) => {
+        $(
+            impl CheckedNum<$unsigned> {
+                /// Adds a signed value of the same size, e.g. a delta to a balance.
+                ///
+                /// Fails if the result is negative or greater than `MAX`.
+                #[cfg_attr(feature = "location", track_caller)]
+                pub fn add_signed<B: Into<CheckedNum<$signed>>>(self, rhs: B) -> Self {
+                    match rhs.into().0 {
+                        Ok(rhs_num) => self.map_primitive(|num| {
+                            num.checked_add_signed(rhs_num).ok_or(OverflowError::new(
+                                OverflowKind::Add,
+                                sign_direction(rhs_num),
+                            ))
+                        }),
+                        Err(err) => self.fail_with(err),
+                    }
+                }
+            }
+
+            impl CheckedNum<$signed> {
+                /// Adds an unsigned value of the same size.
+                #[cfg_attr(feature = "location", track_caller)]
+                pub fn add_unsigned<B: Into<CheckedNum<$unsigned>>>(self, rhs: B) -> Self {
+                    match rhs.into().0 {
+                        Ok(rhs_num) => self.map_primitive(|num| {
+                            num.checked_add_unsigned(rhs_num).ok_or(OverflowError::new(
+                                OverflowKind::Add,
+                                OverflowDirection::Positive,
+                            ))
+                        }),
+                        Err(err) => self.fail_with(err),
+                    }
+                }
+
+                /// Subtracts an unsigned value of the same size.
+                #[cfg_attr(feature = "location", track_caller)]
+                pub fn sub_unsigned<B: Into<CheckedNum<$unsigned>>>(self, rhs: B) -> Self {
+                    match rhs.into().0 {
+                        Ok(rhs_num) => self.map_primitive(|num| {
+                            num.checked_sub_unsigned(rhs_num).ok_or(OverflowError::new(
+                                OverflowKind::Sub,
+                                OverflowDirection::Negative,
+                            ))
+                        }),
+                        Err(err) => self.fail_with(err),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_mixed_sign_ops! {i128 => u128, i64 => u64, i32 => u32, i16 => u16, i8 => u8, isize => usize}
+
 impl<T: CheckedNumTraits> Iterator for CheckedNum<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.take()
+        let num = self.0.ok()?;
+        self.0 = Err(OverflowKind::Unknown.into());
+        Some(num)
     }
 }
 
@@ -151,16 +1035,15 @@ impl<T: CheckedNumTraits + PartialOrd<B>, B: CheckedNumTraits + BuiltinInt>
 }
 
 macro_rules! impl_op {
-    ($trait:ident, $trait_fn:ident) => {
+    ($trait:ident, $trait_fn:ident, $assign_trait:ident, $assign_fn:ident) => {
         impl<T: CheckedNumTraits + $trait<B, Output = T>, B: BuiltinInt> $trait<B>
             for CheckedNum<T>
         {
             type Output = Self;
 
+            #[cfg_attr(feature = "location", track_caller)]
             fn $trait_fn(self, rhs: B) -> <Self as $trait<B>>::Output {
-                self.as_option().map_or(CheckedNum::OVERFLOWED, |num| {
-                    CheckedNum::new(num.$trait_fn(rhs))
-                })
+                CheckedNum(self.0.map(|num| num.$trait_fn(rhs)))
             }
         }
 
@@ -169,81 +1052,447 @@ macro_rules! impl_op {
         {
             type Output = Self;
 
+            #[cfg_attr(feature = "location", track_caller)]
             fn $trait_fn(self, rhs: CheckedNum<B>) -> <Self as $trait<CheckedNum<B>>>::Output {
-                rhs.as_option()
-                    .map_or(CheckedNum::OVERFLOWED, |num| self.$trait_fn(num))
+                match rhs.0 {
+                    Ok(num) => self.$trait_fn(num),
+                    Err(err) => self.fail_with(err),
+                }
+            }
+        }
+
+        impl<T: CheckedNumTraits + $trait<B, Output = T>, B: BuiltinInt> $assign_trait<B>
+            for CheckedNum<T>
+        {
+            #[cfg_attr(feature = "location", track_caller)]
+            fn $assign_fn(&mut self, rhs: B) {
+                *self = self.$trait_fn(rhs);
+            }
+        }
+
+        impl<T: CheckedNumTraits + $trait<B, Output = T>, B: CheckedNumTraits + BuiltinInt>
+            $assign_trait<CheckedNum<B>> for CheckedNum<T>
+        {
+            #[cfg_attr(feature = "location", track_caller)]
+            fn $assign_fn(&mut self, rhs: CheckedNum<B>) {
+                *self = self.$trait_fn(rhs);
             }
         }
     };
 
-    ($trait:ident, $checked_trait:ident, $trait_fn:ident, $checked_fn:ident) => {
-        impl<T: CheckedNumTraits + $checked_trait> $trait<T> for CheckedNum<T> {
+    ($trait:ident, $trait_fn:ident, $assign_trait:ident, $assign_fn:ident, $checked_fn:ident, $kind:ident) => {
+        impl<T: CheckedNumTraits> $trait<T> for CheckedNum<T> {
             type Output = Self;
+            #[cfg_attr(feature = "location", track_caller)]
             fn $trait_fn(self, rhs: T) -> <Self as $trait>::Output {
-                self.as_option()
-                    .map_or(Self::OVERFLOWED, |num| num.$checked_fn(&rhs).into())
+                let rhs = rhs.into_primitive();
+                self.map_primitive(|num| {
+                    num.$checked_fn(&rhs)
+                        .ok_or_else(|| binary_op_failure(OverflowKind::$kind, num, rhs))
+                })
             }
         }
 
-        impl<T: CheckedNumTraits + $checked_trait> $trait for CheckedNum<T> {
+        impl<T: CheckedNumTraits> $trait for CheckedNum<T> {
             type Output = Self;
+            #[cfg_attr(feature = "location", track_caller)]
             fn $trait_fn(self, rhs: Self) -> <Self as $trait>::Output {
-                rhs.as_option()
-                    .map_or(Self::OVERFLOWED, |num| self.$trait_fn(num))
+                match rhs.0 {
+                    Ok(num) => self.$trait_fn(num),
+                    Err(err) => self.fail_with(err),
+                }
             }
         }
+
+        impl<T: CheckedNumTraits> $assign_trait<T> for CheckedNum<T> {
+            #[cfg_attr(feature = "location", track_caller)]
+            fn $assign_fn(&mut self, rhs: T) {
+                *self = self.$trait_fn(rhs);
+            }
+        }
+
+        impl<T: CheckedNumTraits> $assign_trait for CheckedNum<T> {
+            #[cfg_attr(feature = "location", track_caller)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = self.$trait_fn(rhs);
+            }
+        }
+
+        impl_non_zero_op! {$trait, $trait_fn, $assign_trait, $assign_fn, $checked_fn, $kind, i128, i64, i32, i16, i8, isize, u128, u64, u32, u16, u8, usize}
+    };
+}
+
+// `NonZero<T>` values also accept the plain primitive on the right-hand side,
+// e.g. `CheckedNonZeroU32::new(x) + 1`.
+macro_rules! impl_non_zero_op {
+    ($trait:ident, $trait_fn:ident, $assign_trait:ident, $assign_fn:ident, $checked_fn:ident, $kind:ident, $($t:ty),*) => {
+        $(
+            impl $trait<$t> for CheckedNum<NonZero<$t>> {
+                type Output = Self;
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $trait_fn(self, rhs: $t) -> <Self as $trait<$t>>::Output {
+                    self.map_primitive(|num| {
+                        num.$checked_fn(rhs)
+                            .ok_or_else(|| binary_op_failure(OverflowKind::$kind, num, rhs))
+                    })
+                }
+            }
+
+            impl $assign_trait<$t> for CheckedNum<NonZero<$t>> {
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $assign_fn(&mut self, rhs: $t) {
+                    *self = self.$trait_fn(rhs);
+                }
+            }
+        )*
     };
 }
 
 macro_rules! impl_shift_op {
-    ($trait:ident, $checked_trait:ident, $trait_fn:ident, $checked_fn:ident) => {
-        impl<T: CheckedNumTraits + $checked_trait, B: Into<CheckedU32>> $trait<B>
-            for CheckedNum<T>
-        {
+    ($trait:ident, $trait_fn:ident, $assign_trait:ident, $assign_fn:ident, $checked_fn:ident) => {
+        impl<T: CheckedNumTraits, B: Into<CheckedU32>> $trait<B> for CheckedNum<T> {
             type Output = Self;
 
+            #[cfg_attr(feature = "location", track_caller)]
             fn $trait_fn(self, rhs: B) -> <Self as $trait<B>>::Output {
-                self.as_option().map_or(Self::OVERFLOWED, |num| {
-                    rhs.into()
-                        .as_option()
-                        .map_or(Self::OVERFLOWED, |rhs_num| Self(num.$checked_fn(rhs_num)))
-                })
+                match rhs.into().0 {
+                    Ok(rhs_num) => self.map_primitive(|num| {
+                        num.$checked_fn(rhs_num)
+                            .ok_or(OverflowKind::ShiftTooLarge.into())
+                    }),
+                    Err(err) => self.fail_with(err),
+                }
+            }
+        }
+
+        impl_shift_op! {$trait, $trait_fn, $assign_trait, $assign_fn}
+    };
+
+    ($trait:ident, $trait_fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl<T: CheckedNumTraits, B: Into<CheckedU32>> $assign_trait<B> for CheckedNum<T> {
+            #[cfg_attr(feature = "location", track_caller)]
+            fn $assign_fn(&mut self, rhs: B) {
+                *self = self.$trait_fn(rhs);
+            }
+        }
+    };
+}
+
+// Builtin integers on the left-hand side, e.g. `210 + CheckedU16::new(123)`.
+// The orphan rule allows these impls, because `CheckedNum<_>` is a local type.
+macro_rules! impl_reverse_ops {
+    ($($t:ty),*) => {
+        $(
+            impl_reverse_ops! {@binary $t, Add, add, Sub, sub, Mul, mul, Div, div, Rem, rem}
+            impl_reverse_ops! {@binary $t, BitAnd, bitand, BitOr, bitor, BitXor, bitxor}
+            impl_reverse_ops! {@shift $t, Shl, shl, Shr, shr}
+            impl_reverse_ops! {@cmp $t}
+
+            // `NonZero<_>` does not implement `BitAnd` and `BitXor`
+            impl_reverse_ops! {@binary NonZero<$t>, Add, add, Sub, sub, Mul, mul, Div, div, Rem, rem}
+            impl_reverse_ops! {@binary NonZero<$t>, BitOr, bitor}
+            impl_reverse_ops! {@shift NonZero<$t>, Shl, shl, Shr, shr}
+            impl_reverse_ops! {@cmp NonZero<$t>}
+        )*
+    };
+
+    (@binary $t:ty, $($trait:ident, $trait_fn:ident),*) => {
+        $(
+            impl $trait<CheckedNum<$t>> for $t {
+                type Output = CheckedNum<$t>;
+
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $trait_fn(self, rhs: CheckedNum<$t>) -> Self::Output {
+                    CheckedNum::new(self).$trait_fn(rhs)
+                }
+            }
+        )*
+    };
+
+    (@shift $t:ty, $($trait:ident, $trait_fn:ident),*) => {
+        $(
+            impl $trait<CheckedU32> for $t {
+                type Output = CheckedNum<$t>;
+
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $trait_fn(self, rhs: CheckedU32) -> Self::Output {
+                    CheckedNum::new(self).$trait_fn(rhs)
+                }
+            }
+        )*
+    };
+
+    (@cmp $t:ty) => {
+        impl PartialEq<CheckedNum<$t>> for $t {
+            fn eq(&self, rhs: &CheckedNum<$t>) -> bool {
+                rhs.eq(self)
+            }
+        }
+
+        impl PartialOrd<CheckedNum<$t>> for $t {
+            fn partial_cmp(&self, rhs: &CheckedNum<$t>) -> Option<Ordering> {
+                rhs.partial_cmp(self).map(Ordering::reverse)
             }
         }
     };
 }
 
+impl_reverse_ops! {i128, i64, i32, i16, i8, isize, u128, u64, u32, u16, u8, usize}
+
 // Missing from num_traits:
 // - To/From bytes for CheckedNum<u8>
-// - Euclid calculations
-// - MulAdd
-
-impl_op! {Add, CheckedAdd, add, checked_add}
-impl_op! {Sub, CheckedSub, sub, checked_sub}
-impl_op! {Mul, CheckedMul, mul, checked_mul}
-impl_op! {Div, CheckedDiv, div, checked_div}
-impl_op! {Rem, CheckedRem, rem, checked_rem}
-impl_shift_op! {Shl, CheckedShl, shl, checked_shl}
-impl_shift_op! {Shr, CheckedShr, shr, checked_shr}
-
-impl_op! {BitAnd, bitand}
-impl_op! {BitOr, bitor}
-impl_op! {BitXor, bitxor}
-
-impl<T: CheckedNumTraits + CheckedNeg> Neg for CheckedNum<T> {
+
+impl_op! {Add, add, AddAssign, add_assign, checked_add, Add}
+impl_op! {Sub, sub, SubAssign, sub_assign, checked_sub, Sub}
+impl_op! {Mul, mul, MulAssign, mul_assign, checked_mul, Mul}
+impl_op! {Div, div, DivAssign, div_assign, checked_div, Div}
+impl_op! {Rem, rem, RemAssign, rem_assign, checked_rem, Rem}
+impl_shift_op! {Shr, shr, ShrAssign, shr_assign, checked_shr}
+impl_shift_op! {Shl, shl, ShlAssign, shl_assign}
+
+impl_op! {BitAnd, bitand, BitAndAssign, bitand_assign}
+impl_op! {BitOr, bitor, BitOrAssign, bitor_assign}
+impl_op! {BitXor, bitxor, BitXorAssign, bitxor_assign}
+
+/// Overflow-checked left shift.
+///
+/// Fails if `rhs` is greater or equal to the bit width of `T`,
+/// if any significant bit is shifted out, or if the sign changes.
+/// Use [`CheckedNum::shl_bits`] for plain bit shifting.
+impl<T: CheckedNumTraits, B: Into<CheckedU32>> Shl<B> for CheckedNum<T> {
     type Output = Self;
 
+    #[cfg_attr(feature = "location", track_caller)]
+    fn shl(self, rhs: B) -> Self::Output {
+        match rhs.into().0 {
+            Ok(rhs_num) => self.map_primitive(|num| {
+                let shifted = num
+                    .checked_shl(rhs_num)
+                    .ok_or(OverflowError::from(OverflowKind::ShiftTooLarge))?;
+
+                // Shifting back only restores the value if no bits were lost.
+                // `checked_shr` is arithmetic for signed types, so sign changes are caught as well.
+                match shifted.checked_shr(rhs_num) == Some(num) {
+                    true => Ok(shifted),
+                    false => Err(OverflowError::new(OverflowKind::Shl, sign_direction(num))),
+                }
+            }),
+            Err(err) => self.fail_with(err),
+        }
+    }
+}
+
+impl<T: CheckedNumTraits> Neg for CheckedNum<T> {
+    type Output = Self;
+
+    #[cfg_attr(feature = "location", track_caller)]
     fn neg(self) -> Self::Output {
-        self.as_option()
-            .map_or(Self::OVERFLOWED, |num| CheckedNum(num.checked_neg()))
+        self.map_primitive(|num| {
+            // `-MIN` overflows upwards, negating a positive unsigned value downwards
+            let direction = match num < Zero::zero() {
+                true => OverflowDirection::Positive,
+                false => OverflowDirection::Negative,
+            };
+
+            num.checked_neg()
+                .ok_or(OverflowError::new(OverflowKind::Neg, direction))
+        })
     }
 }
 
 impl<T: CheckedNumTraits + Inv<Output = T>> Inv for CheckedNum<T> {
     type Output = Self;
 
+    #[cfg_attr(feature = "location", track_caller)]
     fn inv(self) -> Self::Output {
-        self.as_option()
-            .map_or(Self::OVERFLOWED, |num| CheckedNum::new(num.inv()))
+        CheckedNum(self.0.map(|num| num.inv()))
+    }
+}
+
+// num_traits impls for generic numeric code.
+// A failed value is neither zero nor one and converts to nothing.
+
+impl<T: CheckedNumTraits + Zero> Zero for CheckedNum<T> {
+    fn zero() -> Self {
+        Self::new(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.as_ref().is_ok_and(T::is_zero)
+    }
+}
+
+impl<T: CheckedNumTraits + One + PartialEq> One for CheckedNum<T> {
+    fn one() -> Self {
+        Self::new(T::one())
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.as_ref().is_ok_and(T::is_one)
+    }
+}
+
+impl<T: CheckedNumTraits> Bounded for CheckedNum<T> {
+    fn min_value() -> Self {
+        Self::new(T::MIN)
+    }
+
+    fn max_value() -> Self {
+        Self::new(T::MAX)
+    }
+}
+
+/// Parses like [`CheckedNum::from_str_radix`]: out of range values parse into a failed value.
+impl<T: CheckedNumTraits + Num> Num for CheckedNum<T> {
+    type FromStrRadixErr = ParseError;
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        CheckedNum::from_str_radix(str, radix)
+    }
+}
+
+macro_rules! impl_checked_trait {
+    ($($trait:ident, $fn:ident, $op:ident;)*) => {
+        $(
+            /// Returns `None` if the result failed.
+            impl<T: CheckedNumTraits> $trait for CheckedNum<T> {
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $fn(&self, v: &Self) -> Option<Self> {
+                    (*self).$op(*v).as_option().map(Self::new)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_trait! {
+    CheckedAdd, checked_add, add;
+    CheckedSub, checked_sub, sub;
+    CheckedMul, checked_mul, mul;
+    CheckedDiv, checked_div, div;
+    CheckedRem, checked_rem, rem;
+}
+
+impl<T: CheckedNumTraits> Euclid for CheckedNum<T> {
+    #[cfg_attr(feature = "location", track_caller)]
+    fn div_euclid(&self, v: &Self) -> Self {
+        CheckedNum::div_euclid(*self, *v)
+    }
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn rem_euclid(&self, v: &Self) -> Self {
+        CheckedNum::rem_euclid(*self, *v)
+    }
+}
+
+/// Returns `None` if the result failed.
+impl<T: CheckedNumTraits> CheckedEuclid for CheckedNum<T> {
+    #[cfg_attr(feature = "location", track_caller)]
+    fn checked_div_euclid(&self, v: &Self) -> Option<Self> {
+        CheckedNum::div_euclid(*self, *v).as_option().map(Self::new)
+    }
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn checked_rem_euclid(&self, v: &Self) -> Option<Self> {
+        CheckedNum::rem_euclid(*self, *v).as_option().map(Self::new)
+    }
+}
+
+/// Computes `(self * a) + b`, failing like the separate operations would.
+impl<T: CheckedNumTraits> MulAdd for CheckedNum<T> {
+    type Output = Self;
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn mul_add(self, a: Self, b: Self) -> Self::Output {
+        self * a + b
+    }
+}
+
+impl<T: CheckedNumTraits> MulAdd<T, T> for CheckedNum<T> {
+    type Output = Self;
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn mul_add(self, a: T, b: T) -> Self::Output {
+        self * a + b
+    }
+}
+
+impl<T: CheckedNumTraits> MulAddAssign for CheckedNum<T> {
+    #[cfg_attr(feature = "location", track_caller)]
+    fn mul_add_assign(&mut self, a: Self, b: Self) {
+        *self = self.mul_add(a, b);
+    }
+}
+
+impl<T: CheckedNumTraits> MulAddAssign<T, T> for CheckedNum<T> {
+    #[cfg_attr(feature = "location", track_caller)]
+    fn mul_add_assign(&mut self, a: T, b: T) {
+        *self = self.mul_add(a, b);
+    }
+}
+
+impl<T: CheckedNumTraits> ToPrimitive for CheckedNum<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.as_option()?.into_primitive().to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.as_option()?.into_primitive().to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.as_option()?.into_primitive().to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.as_option()?.into_primitive().to_u128()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        self.as_option()?.into_primitive().to_f32()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.as_option()?.into_primitive().to_f64()
+    }
+}
+
+/// Returns `None` if the value does not fit into `T`, following the num_traits convention.
+impl<T: CheckedNumTraits> FromPrimitive for CheckedNum<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_primitive_with(|| T::Primitive::from_i64(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_primitive_with(|| T::Primitive::from_u64(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Self::from_primitive_with(|| T::Primitive::from_i128(n))
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::from_primitive_with(|| T::Primitive::from_u128(n))
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::from_primitive_with(|| T::Primitive::from_f32(n))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::from_primitive_with(|| T::Primitive::from_f64(n))
+    }
+}
+
+impl<T: CheckedNumTraits> NumCast for CheckedNum<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        Self::from_primitive_with(|| <T::Primitive as NumCast>::from(n))
+    }
+}
+
+impl<T: CheckedNumTraits> CheckedNum<T> {
+    fn from_primitive_with(convert: impl FnOnce() -> Option<T::Primitive>) -> Option<Self> {
+        convert().and_then(T::from_primitive).map(Self::new)
     }
 }
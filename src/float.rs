@@ -0,0 +1,132 @@
+use num_traits::{NumCast, ToPrimitive, float::FloatCore};
+
+use crate::{
+    CheckedNum,
+    checked_num::CheckedNumTraits,
+    overflow::{OverflowDirection, OverflowError, OverflowKind},
+};
+
+/// How a float with a fractional part is rounded to an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards zero, like `as`.
+    Truncate,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest integer, ties to the even one.
+    NearestEven,
+}
+
+impl Rounding {
+    fn round<F: FloatCore>(self, num: F) -> F {
+        match self {
+            Rounding::Truncate => num.trunc(),
+            Rounding::Floor => num.floor(),
+            Rounding::Ceil => num.ceil(),
+            Rounding::NearestEven => {
+                // `round` rounds ties away from zero
+                let rounded = num.round();
+                let diff = rounded - num;
+                let is_tie = diff.abs() == F::from(0.5).unwrap();
+                let is_odd = rounded % F::from(2.0).unwrap() != F::zero();
+
+                match is_tie && is_odd {
+                    true => rounded - diff - diff,
+                    false => rounded,
+                }
+            }
+        }
+    }
+}
+
+impl<T: CheckedNumTraits> CheckedNum<T> {
+    /// Converts a float to an integer, rounding a fractional part with `rounding`.
+    ///
+    /// NaN fails with [`OverflowKind::NaN`],
+    /// infinities and values out of the range of `T` fail with [`OverflowKind::Conversion`].
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedI32, CheckedU8, OverflowKind, Rounding};
+    ///
+    /// assert_eq!(CheckedI32::from_f64_rounded(-2.5, Rounding::Truncate), -2);
+    /// assert_eq!(CheckedI32::from_f64_rounded(-2.5, Rounding::Floor), -3);
+    /// assert_eq!(CheckedI32::from_f64_rounded(-2.5, Rounding::Ceil), -2);
+    /// assert_eq!(CheckedI32::from_f64_rounded(-2.5, Rounding::NearestEven), -2);
+    ///
+    /// assert!(CheckedU8::from_f64_rounded(255.5, Rounding::Ceil).did_overflow());
+    /// assert!(CheckedU8::from_f64_rounded(f64::INFINITY, Rounding::Floor).did_overflow());
+    /// assert_eq!(
+    ///     CheckedU8::from_f64_rounded(f64::NAN, Rounding::Floor).overflow_kind(),
+    ///     Some(OverflowKind::NaN)
+    /// );
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn from_f64_rounded(num: f64, rounding: Rounding) -> Self {
+        Self::from_float(num, rounding)
+    }
+
+    /// Converts a float to an integer, rounding a fractional part with `rounding`.
+    ///
+    /// See [`CheckedNum::from_f64_rounded`].
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn from_f32_rounded(num: f32, rounding: Rounding) -> Self {
+        Self::from_float(num, rounding)
+    }
+
+    /// Converts to `f64`, failing with [`OverflowKind::Inexact`]
+    /// if the value can't be represented exactly, e.g. `u64` values above 2^53.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedU64, OverflowKind};
+    ///
+    /// assert_eq!(CheckedU64::new(1 << 53).to_f64_exact(), Ok(9007199254740992.0));
+    /// assert_eq!(
+    ///     CheckedU64::new((1 << 53) + 1).to_f64_exact().map_err(|err| err.kind()),
+    ///     Err(OverflowKind::Inexact)
+    /// );
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn to_f64_exact(self) -> Result<f64, OverflowError> {
+        self.to_float_exact(ToPrimitive::to_f64)
+    }
+
+    /// Converts to `f32`, failing with [`OverflowKind::Inexact`]
+    /// if the value can't be represented exactly, e.g. `u32` values above 2^24.
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn to_f32_exact(self) -> Result<f32, OverflowError> {
+        self.to_float_exact(ToPrimitive::to_f32)
+    }
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn from_float<F: FloatCore>(num: F, rounding: Rounding) -> Self {
+        if num.is_nan() {
+            return Self::overflowed(OverflowKind::NaN);
+        }
+
+        let direction = match num.is_sign_negative() {
+            true => OverflowDirection::Negative,
+            false => OverflowDirection::Positive,
+        };
+
+        Self::from_primitive_result(
+            <T::Primitive as NumCast>::from(rounding.round(num))
+                .ok_or(OverflowError::new(OverflowKind::Conversion, direction)),
+        )
+    }
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn to_float_exact<F: FloatCore>(
+        self,
+        to_float: impl FnOnce(&T::Primitive) -> Option<F>,
+    ) -> Result<F, OverflowError> {
+        let num = self.into_result()?.into_primitive();
+        let float = to_float(&num);
+
+        match float.and_then(<T::Primitive as NumCast>::from) == Some(num) {
+            true => Ok(float.unwrap()),
+            false => Err(OverflowKind::Inexact.into()),
+        }
+    }
+}
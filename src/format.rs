@@ -0,0 +1,65 @@
+use core::fmt;
+
+use crate::{CheckedNum, checked_num::CheckedNumTraits};
+
+/// Printed for failed values.
+const DEFAULT_MARKER: &str = "<overflow>";
+
+/// Formats a `CheckedNum` with a custom marker for failed values.
+///
+/// Created by [`CheckedNum::with_marker`].
+#[derive(Debug, Clone, Copy)]
+pub struct WithMarker<'a, T: CheckedNumTraits> {
+    num: CheckedNum<T>,
+    marker: &'a str,
+}
+
+impl<T: CheckedNumTraits> CheckedNum<T> {
+    /// Formats failed values as `marker` instead of `<overflow>`.
+    ///
+    /// ```rust
+    /// use checked_num::CheckedU8;
+    ///
+    /// let failed = CheckedU8::new(255) + 1;
+    ///
+    /// assert_eq!(format!("{failed}"), "<overflow>");
+    /// assert_eq!(format!("[{:>5}]", failed.with_marker("n/a")), "[  n/a]");
+    /// assert_eq!(format!("[{:>5}]", CheckedU8::new(7).with_marker("n/a")), "[    7]");
+    /// ```
+    pub fn with_marker(self, marker: &str) -> WithMarker<'_, T> {
+        WithMarker { num: self, marker }
+    }
+}
+
+/// Formats the value with `fmt`, or pads `marker` if it failed.
+fn fmt_checked<T: CheckedNumTraits>(
+    num: &CheckedNum<T>,
+    marker: &str,
+    f: &mut fmt::Formatter<'_>,
+    fmt: fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    match num.as_option() {
+        Some(num) => fmt(&num, f),
+        None => f.pad(marker),
+    }
+}
+
+macro_rules! impl_fmt {
+    ($($trait:ident),*) => {
+        $(
+            impl<T: CheckedNumTraits + fmt::$trait> fmt::$trait for CheckedNum<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_checked(self, DEFAULT_MARKER, f, fmt::$trait::fmt)
+                }
+            }
+
+            impl<T: CheckedNumTraits + fmt::$trait> fmt::$trait for WithMarker<'_, T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_checked(&self.num, self.marker, f, fmt::$trait::fmt)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt! {Display, LowerHex, UpperHex, Octal, Binary, LowerExp, UpperExp}
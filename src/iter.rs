@@ -0,0 +1,150 @@
+use core::iter::{Product, Sum};
+
+use num_traits::{One, Zero};
+
+use crate::{CheckedNum, checked_num::CheckedNumTraits};
+
+/// Checked folds over iterators, with the result type chosen by the caller.
+///
+/// All methods stop consuming the iterator at the first failure.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedIterExt, CheckedU8, OverflowKind};
+///
+/// let values = [100u64, 200, 300];
+///
+/// assert_eq!(values.iter().checked_sum::<u64>(), 600);
+/// assert_eq!(values.iter().checked_product::<u64>(), 6_000_000);
+///
+/// let mut values = [200u8, 100, 1].into_iter();
+/// assert_eq!(
+///     values.by_ref().checked_sum::<u8>().overflow_kind(),
+///     Some(OverflowKind::Add)
+/// );
+/// // `1` was never reached
+/// assert_eq!(values.next(), Some(1));
+///
+/// assert!((0..300).checked_count::<u8>().did_overflow());
+/// ```
+pub trait CheckedIterExt: Iterator + Sized {
+    /// Sums the items, stopping at the first failure.
+    ///
+    /// Items can be `T`, `CheckedNum<T>` or references to either.
+    /// Unlike `Iterator::sum`, this records the caller with the `location` feature.
+    #[cfg_attr(feature = "location", track_caller)]
+    fn checked_sum<T: CheckedNumTraits>(self) -> CheckedNum<T>
+    where
+        Self::Item: CheckedItem<T>,
+    {
+        sum(self.map(CheckedItem::into_checked))
+    }
+
+    /// Multiplies the items, stopping at the first failure.
+    ///
+    /// Items can be `T`, `CheckedNum<T>` or references to either.
+    /// Unlike `Iterator::product`, this records the caller with the `location` feature.
+    #[cfg_attr(feature = "location", track_caller)]
+    fn checked_product<T: CheckedNumTraits>(self) -> CheckedNum<T>
+    where
+        Self::Item: CheckedItem<T>,
+    {
+        product(self.map(CheckedItem::into_checked))
+    }
+
+    /// Counts the items, stopping as soon as the count doesn't fit into `T`.
+    #[cfg_attr(feature = "location", track_caller)]
+    fn checked_count<T: CheckedNumTraits>(self) -> CheckedNum<T> {
+        let mut count = CheckedNum::new(T::Primitive::zero());
+
+        for _ in self {
+            count += T::Primitive::one();
+
+            if count.did_overflow() {
+                break;
+            }
+        }
+
+        count.map_primitive(Ok)
+    }
+}
+
+impl<I: Iterator> CheckedIterExt for I {}
+
+/// An iterator item that can be summed or multiplied into a `CheckedNum<T>`.
+pub trait CheckedItem<T: CheckedNumTraits> {
+    fn into_checked(self) -> CheckedNum<T>;
+}
+
+impl<T: CheckedNumTraits> CheckedItem<T> for T {
+    fn into_checked(self) -> CheckedNum<T> {
+        CheckedNum::new(self)
+    }
+}
+
+impl<T: CheckedNumTraits> CheckedItem<T> for &T {
+    fn into_checked(self) -> CheckedNum<T> {
+        CheckedNum::new(*self)
+    }
+}
+
+impl<T: CheckedNumTraits> CheckedItem<T> for CheckedNum<T> {
+    fn into_checked(self) -> CheckedNum<T> {
+        self
+    }
+}
+
+impl<T: CheckedNumTraits> CheckedItem<T> for &CheckedNum<T> {
+    fn into_checked(self) -> CheckedNum<T> {
+        *self
+    }
+}
+
+// The accumulator is a primitive, so `NonZero<_>` values only fail
+// if the final result is zero, not if an intermediate one is.
+
+#[cfg_attr(feature = "location", track_caller)]
+fn sum<T: CheckedNumTraits>(iter: impl Iterator<Item = CheckedNum<T>>) -> CheckedNum<T> {
+    let mut acc = CheckedNum::new(T::Primitive::zero());
+
+    for num in iter {
+        acc += num.map(T::into_primitive);
+
+        if acc.did_overflow() {
+            break;
+        }
+    }
+
+    acc.map_primitive(Ok)
+}
+
+#[cfg_attr(feature = "location", track_caller)]
+fn product<T: CheckedNumTraits>(iter: impl Iterator<Item = CheckedNum<T>>) -> CheckedNum<T> {
+    let mut acc = CheckedNum::new(T::Primitive::one());
+
+    for num in iter {
+        acc *= num.map(T::into_primitive);
+
+        if acc.did_overflow() {
+            break;
+        }
+    }
+
+    acc.map_primitive(Ok)
+}
+
+// `Iterator::sum` and `Iterator::product` don't track their caller,
+// so with the `location` feature failures are recorded inside `core`.
+// `CheckedIterExt` records the caller instead.
+
+impl<T: CheckedNumTraits, I: CheckedItem<T>> Sum<I> for CheckedNum<T> {
+    fn sum<It: Iterator<Item = I>>(iter: It) -> Self {
+        sum(iter.map(CheckedItem::into_checked))
+    }
+}
+
+impl<T: CheckedNumTraits, I: CheckedItem<T>> Product<I> for CheckedNum<T> {
+    fn product<It: Iterator<Item = I>>(iter: It) -> Self {
+        product(iter.map(CheckedItem::into_checked))
+    }
+}
@@ -1,4 +1,5 @@
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2, try_trait_v2_residual))]
 
 //! # CheckedNum
 //!
@@ -25,56 +26,128 @@
 //! );
 //! ```
 //!
-//! ## Limitations
+//! ## The `?` operator
 //!
-//! Due to the orphan rule, `CheckedNum` types must appear on the left-hand side of mixed-type operations:
+//! With the unstable `nightly` feature `CheckedNum` implements `Try`,
+//! so `?` works inside functions returning `CheckedNum`, `Option` or `Result<_, E>`
+//! where `E: From<OverflowError>`.
+//!
+//! On stable, [`CheckedNum::into_result`] provides the same early return:
 //!
 //! ```rust
-//! use checked_num::CheckedU16;
-//! let a = CheckedU16::new(123);
-//! let b = 210;
-//! assert_eq!(a + b, 333) // correct
+//! use checked_num::{CheckedU32, OverflowError};
+//!
+//! fn area(width: u32, height: u32) -> Result<u32, OverflowError> {
+//!     (CheckedU32::new(width) * height).into_result()
+//! }
+//!
+//! fn volume(width: u32, height: u32, depth: u32) -> Result<u32, OverflowError> {
+//!     let area = area(width, height)?;
+//!     (CheckedU32::new(area) * depth).into_result()
+//! }
+//!
+//! assert_eq!(volume(2, 3, 4), Ok(24));
+//! assert!(volume(u32::MAX, 2, 1).is_err());
 //! ```
 //!
-//! ```compile_fail
+//! ## Mixed operations
+//!
+//! Builtin integers of the same type can appear on either side of an operator:
+//!
+//! ```rust
 //! use checked_num::CheckedU16;
 //!
 //! let a = CheckedU16::new(123);
 //! let b = 210;
 //!
-//! assert_eq!(b + a, 333) // fails to compile
+//! assert_eq!(a + b, 333);
+//! assert_eq!(b + a, 333);
+//! assert_eq!(b, a + 87);
 //! ```
+//!
+//! ## The `checked!` macro
+//!
+//! Operator precedence can leave parts of a calculation unchecked:
+//! in `CheckedU16::new(a) + b * c` the multiplication is performed on `u16`.
+//! With the `macros` feature, `checked!` turns every operand into a `CheckedNum`:
+//!
+//! ```rust
+//! # #[cfg(feature = "macros")]
+//! # {
+//! use checked_num::checked;
+//!
+//! let (a, b, c) = (1u16, 300, 300);
+//!
+//! assert!(checked!(a + b * c).did_overflow());
+//! assert_eq!(checked!(u16: a + 2 * 3), 7);
+//! # }
+//! ```
+//!
+//! `#[checked_fn]` does the same for all arithmetic in a function body
+//! and rejects arithmetic it can't check, e.g. `wrapping_add` or arithmetic in an index.
+//!
+//! `explain!` evaluates an expression like `checked!` and returns an `OverflowReport`
+//! naming the failing sub-expression and its operand values.
 
 use core::num::NonZero;
 
 pub use checked_num::CheckedNum;
+#[cfg(feature = "macros")]
+pub use checked_num_macros::{checked, checked_fn, explain};
+pub use float::Rounding;
+pub use format::WithMarker;
+pub use iter::CheckedIterExt;
+pub use overflow::{OverflowDirection, OverflowError, OverflowKind};
+pub use parse::{ParseError, ParseErrorKind};
+#[cfg(feature = "macros")]
+pub use report::OverflowReport;
+pub use total::TotalCheckedNum;
+pub use width::{Narrow, Promote};
 
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private;
 mod builtin_int;
 mod checked_num;
+mod float;
+mod format;
+mod iter;
+mod overflow;
+mod parse;
+#[cfg(feature = "macros")]
+mod report;
+mod total;
+#[cfg(feature = "nightly")]
+mod try_trait;
+mod width;
 
 pub type CheckedU128 = CheckedNum<u128>;
 pub type CheckedU64 = CheckedNum<u64>;
 pub type CheckedU32 = CheckedNum<u32>;
 pub type CheckedU16 = CheckedNum<u16>;
 pub type CheckedU8 = CheckedNum<u8>;
+pub type CheckedUsize = CheckedNum<usize>;
 
 pub type CheckedI128 = CheckedNum<i128>;
 pub type CheckedI64 = CheckedNum<i64>;
 pub type CheckedI32 = CheckedNum<i32>;
 pub type CheckedI16 = CheckedNum<i16>;
 pub type CheckedI8 = CheckedNum<i8>;
+pub type CheckedIsize = CheckedNum<isize>;
 
 pub type CheckedNonZeroU128 = CheckedNum<NonZero<u128>>;
 pub type CheckedNonZeroU64 = CheckedNum<NonZero<u64>>;
 pub type CheckedNonZeroU32 = CheckedNum<NonZero<u32>>;
 pub type CheckedNonZeroU16 = CheckedNum<NonZero<u16>>;
 pub type CheckedNonZeroU8 = CheckedNum<NonZero<u8>>;
+pub type CheckedNonZeroUsize = CheckedNum<NonZero<usize>>;
 
 pub type CheckedNonZeroI128 = CheckedNum<NonZero<i128>>;
 pub type CheckedNonZeroI64 = CheckedNum<NonZero<i64>>;
 pub type CheckedNonZeroI32 = CheckedNum<NonZero<i32>>;
 pub type CheckedNonZeroI16 = CheckedNum<NonZero<i16>>;
 pub type CheckedNonZeroI8 = CheckedNum<NonZero<i8>>;
+pub type CheckedNonZeroIsize = CheckedNum<NonZero<isize>>;
 
 #[test]
 fn normal_add() {
@@ -119,3 +192,715 @@ fn test_non_zero() {
 
     assert_eq!(b | a, a)
 }
+
+#[test]
+fn non_zero_arithmetic() {
+    let nz = |num| NonZero::new(num).unwrap();
+    let a = CheckedNonZeroU32::new(nz(10));
+
+    assert_eq!(a + 1, nz(11));
+    assert_eq!(a * a - 1, nz(99));
+    assert_eq!(a / 3 % 2, nz(1));
+    assert_eq!(a.pow(2), nz(100));
+    assert!((a * u32::MAX).did_overflow());
+}
+
+#[test]
+fn non_zero_zero_result() {
+    let nz = |num| NonZero::new(num).unwrap();
+    let a = CheckedNonZeroI8::new(nz(5));
+
+    // a result of zero can not be represented by `NonZero`
+    assert!((a - 5).did_overflow());
+    assert!((a / 6).did_overflow());
+    assert_eq!(a + -6, nz(-1));
+    assert_eq!(-a, nz(-5));
+}
+
+#[test]
+fn shl_lost_bits() {
+    assert_eq!(CheckedU8::new(0b0100_0000) << 1, 0b1000_0000);
+    assert!((CheckedU8::new(0b1000_0000) << 1).did_overflow());
+    assert!((CheckedU8::new(1) << 8).did_overflow());
+}
+
+#[test]
+fn shl_sign_change() {
+    assert_eq!(CheckedI8::new(-1) << 7, i8::MIN);
+    assert!((CheckedI8::new(64) << 1).did_overflow());
+    assert!((CheckedI8::new(-65) << 1).did_overflow());
+}
+
+#[test]
+fn usize_conversions() {
+    let len = CheckedUsize::new(usize::MAX);
+
+    assert!((len + 1).did_overflow());
+    assert!(CheckedU8::checked_from_usize(len).did_overflow());
+    assert!(CheckedI8::new(-1).checked_to_usize().did_overflow());
+    assert_eq!(CheckedI8::new(-1).checked_to_isize(), -1);
+    assert_eq!(CheckedU16::checked_from_isize(1234), 1234);
+
+    let nz_len = CheckedNonZeroUsize::checked_from_usize(0);
+    assert!(nz_len.did_overflow());
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn usize_pointer_width() {
+    assert_eq!(CheckedU64::new(u64::MAX).checked_to_usize(), usize::MAX);
+    assert!(CheckedU64::new(u64::MAX).checked_to_isize().did_overflow());
+    assert_eq!(CheckedU64::checked_from_usize(usize::MAX), u64::MAX);
+}
+
+#[test]
+fn overflow_kind() {
+    assert_eq!(
+        (CheckedU8::new(255) + 1).overflow_kind(),
+        Some(OverflowKind::Add)
+    );
+    assert_eq!(
+        (CheckedU8::new(0) - 1).overflow_kind(),
+        Some(OverflowKind::Sub)
+    );
+    assert_eq!(
+        (CheckedI8::new(-128) / -1).overflow_kind(),
+        Some(OverflowKind::Div)
+    );
+    assert_eq!(
+        (CheckedI8::new(1) % 0).overflow_kind(),
+        Some(OverflowKind::DivByZero)
+    );
+    assert_eq!(
+        (-CheckedI8::new(-128)).overflow_kind(),
+        Some(OverflowKind::Neg)
+    );
+    assert_eq!(
+        (CheckedI8::new(1) >> 8).overflow_kind(),
+        Some(OverflowKind::ShiftTooLarge)
+    );
+    assert_eq!(CheckedU8::new(1).overflow_kind(), None);
+}
+
+#[test]
+fn overflow_kind_propagates() {
+    let a = CheckedU8::new(1) / 0;
+    let b = CheckedU8::new(255) + 1;
+
+    assert_eq!((a * 2 + b).overflow_kind(), Some(OverflowKind::DivByZero));
+    assert_eq!((b + a).overflow_kind(), Some(OverflowKind::Add));
+    assert_eq!(
+        (CheckedU8::new(1) - b).overflow_kind(),
+        Some(OverflowKind::Add)
+    );
+
+    let err = (a + 1).into_result().unwrap_err();
+    assert_eq!(err.kind(), OverflowKind::DivByZero);
+}
+
+#[test]
+fn overflow_direction() {
+    let direction = |num: CheckedI8| num.into_result().unwrap_err().direction();
+
+    assert_eq!(
+        direction(CheckedI8::new(100) + 100),
+        OverflowDirection::Positive
+    );
+    assert_eq!(
+        direction(CheckedI8::new(-100) + -100),
+        OverflowDirection::Negative
+    );
+    assert_eq!(
+        direction(CheckedI8::new(-100) - 100),
+        OverflowDirection::Negative
+    );
+    assert_eq!(
+        direction(CheckedI8::new(100) - -100),
+        OverflowDirection::Positive
+    );
+    assert_eq!(
+        direction(CheckedI8::new(-100) * -2),
+        OverflowDirection::Positive
+    );
+    assert_eq!(
+        direction(CheckedI8::new(-100) * 2),
+        OverflowDirection::Negative
+    );
+    assert_eq!(
+        direction(CheckedI8::new(-128) / -1),
+        OverflowDirection::Positive
+    );
+    assert_eq!(
+        direction(CheckedI8::new(1) / 0),
+        OverflowDirection::Undefined
+    );
+    assert_eq!(
+        direction(-CheckedI8::new(-128)),
+        OverflowDirection::Positive
+    );
+    assert_eq!(
+        direction(CheckedI8::new(-2).pow(9)),
+        OverflowDirection::Negative
+    );
+    assert_eq!(
+        direction(CheckedI8::new(-96) << 1),
+        OverflowDirection::Negative
+    );
+}
+
+#[test]
+fn saturate() {
+    assert_eq!((CheckedU8::new(3) - 5).saturate(), Some(0));
+    assert_eq!((-CheckedU8::new(3)).saturate(), Some(0));
+    assert_eq!((CheckedU8::new(200) * 2 - 5).saturate(), Some(u8::MAX));
+    assert_eq!((CheckedU8::new(200) % 0).saturate_or(7), 7);
+    assert_eq!(CheckedU8::new(200).saturate_or(7), 200);
+
+    let nz = |num| NonZero::new(num).unwrap();
+    assert_eq!((CheckedNonZeroU8::new(nz(3)) - 5).saturate(), Some(nz(1)));
+}
+
+#[cfg(feature = "location")]
+#[test]
+fn overflow_location() {
+    let a = CheckedU8::new(200);
+
+    let first_line = line!() + 1;
+    let b = a + 100;
+    let c = CheckedU8::new(1) - b * 2;
+
+    assert_eq!(c.overflow_location().unwrap().line(), first_line);
+    assert_eq!(c.overflow_location().unwrap().file(), file!());
+
+    let shift_line = line!() + 1;
+    let d = CheckedI8::new(1) << 9;
+    assert_eq!(d.overflow_location().unwrap().line(), shift_line);
+
+    let sum_line = line!() + 1;
+    let e = [200u8, 100].iter().checked_sum::<u8>();
+    assert_eq!(e.overflow_location().unwrap().line(), sum_line);
+    assert_eq!(e.overflow_location().unwrap().file(), file!());
+}
+
+#[cfg(not(feature = "location"))]
+#[test]
+fn overflow_size() {
+    use core::mem::size_of;
+
+    assert_eq!(size_of::<CheckedU8>(), 2);
+    assert_eq!(size_of::<CheckedU32>(), 8);
+
+    // The error does not fit into the niche of `NonZero<_>`.
+    assert_eq!(size_of::<CheckedNonZeroU8>(), 2);
+    assert_eq!(size_of::<CheckedNonZeroI16>(), 4);
+    assert_eq!(size_of::<CheckedNonZeroU32>(), 8);
+    assert_eq!(size_of::<CheckedNonZeroI64>(), 16);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn try_operator() {
+    #[derive(Debug, PartialEq)]
+    struct AppError(OverflowKind);
+
+    impl From<OverflowError> for AppError {
+        fn from(err: OverflowError) -> Self {
+            AppError(err.kind())
+        }
+    }
+
+    fn checked(a: u8, b: u8) -> CheckedU8 {
+        let sum = (CheckedU8::new(a) + b)?;
+        CheckedU8::new(sum) * 2
+    }
+
+    fn result(a: u8, b: u8) -> Result<u8, AppError> {
+        Ok((CheckedU8::new(a) + b)?)
+    }
+
+    assert_eq!(checked(1, 2), 6);
+    assert_eq!(checked(255, 1).overflow_kind(), Some(OverflowKind::Add));
+    assert_eq!(result(1, 2), Ok(3));
+    assert_eq!(result(255, 1), Err(AppError(OverflowKind::Add)));
+}
+
+#[test]
+fn assign_ops() {
+    let mut acc = CheckedU8::new(0);
+
+    for num in [10, 20, 30] {
+        acc += num;
+    }
+    acc *= CheckedU8::new(2);
+    acc -= 20;
+    acc /= 4;
+    acc %= 21;
+    acc <<= 1;
+    acc >>= 2u32;
+    acc |= 0b1000;
+    acc &= 0b1100;
+    acc ^= 0b0100;
+
+    assert_eq!(acc, 0b1100);
+}
+
+#[test]
+fn assign_keeps_failure() {
+    let mut acc = CheckedU8::new(200);
+
+    acc += 100;
+    acc -= 100;
+    acc &= 0;
+
+    assert_eq!(acc.overflow_kind(), Some(OverflowKind::Add));
+
+    let mut nz = CheckedNonZeroU8::new(NonZero::new(1).unwrap());
+    nz -= 1;
+    assert_eq!(nz.overflow_kind(), Some(OverflowKind::Zero));
+}
+
+#[test]
+fn builtin_lhs() {
+    let a = CheckedI16::new(100);
+
+    assert_eq!(1000 - a, 900);
+    assert_eq!(1000 / a, 10);
+    assert_eq!(0b0110 ^ CheckedI16::new(0b0011), 0b0101);
+    assert_eq!(1 << CheckedU32::new(3), 8);
+    assert!((i16::MIN - a).did_overflow());
+
+    assert!(99 < a);
+    assert!(100 <= a);
+    assert!(100 == a);
+    assert!(100 != a * 1000);
+}
+
+#[test]
+fn casts() {
+    assert_eq!(CheckedI8::new(-1).cast::<i128>(), -1);
+    assert_eq!(
+        CheckedI8::new(-1).cast::<u8>().overflow_kind(),
+        Some(OverflowKind::Conversion)
+    );
+    assert_eq!(
+        CheckedI64::new(i64::MIN).cast::<i32>().saturate(),
+        Some(i32::MIN)
+    );
+    assert_eq!(
+        CheckedU8::new(0).cast::<NonZero<u64>>().overflow_kind(),
+        Some(OverflowKind::Zero)
+    );
+    assert_eq!(
+        (CheckedU8::new(255) + 1).cast::<u16>().overflow_kind(),
+        Some(OverflowKind::Add)
+    );
+}
+
+#[test]
+fn from_and_try_from() {
+    let wide: CheckedI32 = CheckedU16::new(u16::MAX).into();
+    assert_eq!(wide, 65535);
+
+    let nz = NonZero::new(7u8).unwrap();
+    let wide: CheckedNonZeroU64 = CheckedNonZeroU8::new(nz).into();
+    assert_eq!(wide, NonZero::new(7).unwrap());
+
+    assert_eq!(
+        CheckedU8::try_from(CheckedI32::new(200)),
+        Ok(CheckedU8::new(200))
+    );
+    assert_eq!(
+        CheckedU8::try_from(CheckedI32::new(256)).map_err(|err| err.kind()),
+        Err(OverflowKind::Conversion)
+    );
+    assert!(CheckedNonZeroU32::try_from(CheckedU32::new(0)).is_err());
+}
+
+#[test]
+fn float_rounding() {
+    let round = |num, rounding| CheckedI8::from_f32_rounded(num, rounding);
+
+    assert_eq!(round(2.5, Rounding::NearestEven), 2);
+    assert_eq!(round(3.5, Rounding::NearestEven), 4);
+    assert_eq!(round(-3.5, Rounding::NearestEven), -4);
+    assert_eq!(round(2.6, Rounding::NearestEven), 3);
+    assert_eq!(round(-128.9, Rounding::Truncate), -128);
+    assert_eq!(round(-128.1, Rounding::Floor).saturate(), Some(i8::MIN));
+    assert_eq!(
+        CheckedNonZeroU8::from_f64_rounded(0.4, Rounding::Truncate).overflow_kind(),
+        Some(OverflowKind::Zero)
+    );
+}
+
+#[test]
+fn float_exact() {
+    assert_eq!(CheckedU32::new(1 << 24).to_f32_exact(), Ok(16777216.0));
+    assert!(CheckedU32::new((1 << 24) + 1).to_f32_exact().is_err());
+    assert_eq!(
+        CheckedI64::new(i64::MIN).to_f64_exact(),
+        Ok(-9223372036854775808.0)
+    );
+    assert!(CheckedU64::new(u64::MAX).to_f64_exact().is_err());
+    assert_eq!(
+        (CheckedU8::new(1) - 2)
+            .to_f64_exact()
+            .map_err(|err| err.kind()),
+        Err(OverflowKind::Sub)
+    );
+}
+
+#[test]
+fn num_traits_generic() {
+    use num_traits::{Bounded, CheckedAdd, FromPrimitive, Num, NumCast, One, ToPrimitive, Zero};
+
+    fn triangle<N: Num + Copy>(n: N) -> N {
+        n * (n + N::one()) / (N::one() + N::one())
+    }
+
+    assert_eq!(triangle(CheckedU8::new(10)), 55);
+    assert!(triangle(CheckedU8::new(30)).did_overflow());
+
+    let failed = CheckedU8::max_value() + 1;
+    assert!(CheckedU8::zero().is_zero());
+    assert!(!failed.is_zero() && !failed.is_one());
+    assert_eq!(
+        CheckedU8::new(1).checked_add(&CheckedU8::new(2)),
+        Some(CheckedU8::new(3))
+    );
+    assert_eq!(CheckedU8::max_value().checked_add(&CheckedU8::one()), None);
+
+    assert_eq!(failed.to_u32(), None);
+    assert_eq!(CheckedU128::new(u128::MAX).to_u128(), Some(u128::MAX));
+    assert_eq!(
+        <CheckedI8 as NumCast>::from(-128i64),
+        Some(CheckedI8::new(-128))
+    );
+    assert_eq!(<CheckedNonZeroU8 as NumCast>::from(0), None);
+    assert_eq!(CheckedU8::from_f64(1.0), Some(CheckedU8::new(1)));
+    assert_eq!(CheckedU8::from_usize(300), None);
+    assert_eq!(CheckedI8::new(-1).to_isize(), Some(-1));
+    assert_eq!(
+        CheckedU16::from_str_radix("ff", 16).ok(),
+        Some(CheckedU16::new(255))
+    );
+}
+
+#[test]
+fn euclid() {
+    use num_traits::CheckedEuclid;
+
+    // day of the week for negative day offsets
+    assert_eq!(CheckedI64::new(-1).rem_euclid(7), 6);
+    assert_eq!(CheckedI64::new(-8).div_euclid(CheckedI64::new(7)), -2);
+    assert_eq!(CheckedU8::new(7).rem_euclid(3), 1);
+
+    assert_eq!(
+        CheckedI8::new(i8::MIN).rem_euclid(-1).overflow_kind(),
+        Some(OverflowKind::Rem)
+    );
+    assert_eq!(
+        CheckedI8::new(1).div_euclid(0).overflow_kind(),
+        Some(OverflowKind::DivByZero)
+    );
+    assert_eq!(
+        CheckedI8::new(i8::MIN).checked_div_euclid(&CheckedI8::new(-1)),
+        None
+    );
+}
+
+#[test]
+fn mul_add() {
+    use num_traits::{MulAdd, MulAddAssign};
+
+    assert_eq!(CheckedU8::new(10).mul_add(20, 55), 255);
+    assert_eq!(
+        CheckedU8::new(10).mul_add(20, 56).overflow_kind(),
+        Some(OverflowKind::Add)
+    );
+    assert_eq!(
+        CheckedU8::new(10)
+            .mul_add(CheckedU8::new(26), CheckedU8::new(0))
+            .overflow_kind(),
+        Some(OverflowKind::Mul)
+    );
+
+    let mut acc = CheckedI16::new(3);
+    acc.mul_add_assign(-4, 2);
+    assert_eq!(acc, -10);
+}
+
+#[test]
+fn pow_checked_exponent() {
+    let failed_exp = CheckedU32::new(0) - 1;
+
+    assert_eq!(CheckedU64::new(10).pow(CheckedU32::new(19)), 10u64.pow(19));
+    assert_eq!(
+        CheckedU64::new(10).pow(failed_exp).overflow_kind(),
+        Some(OverflowKind::Sub)
+    );
+}
+
+#[test]
+fn combinators() {
+    let failed = CheckedU8::new(0) - 1;
+
+    assert_eq!(
+        failed.map(u32::from).overflow_kind(),
+        Some(OverflowKind::Sub)
+    );
+    assert_eq!(failed.unwrap_or(7), 7);
+    assert_eq!(
+        failed.unwrap_or_else(|err| err.kind() as u8),
+        OverflowKind::Sub as u8
+    );
+    assert_eq!(failed.unwrap_or_default(), 0);
+    assert_eq!(failed.or(CheckedU8::new(3)), 3);
+    assert_eq!(failed.or_else(|_| CheckedU8::new(4)), 4);
+    assert!(!failed.is_ok_and(|_| true));
+
+    assert_eq!(CheckedU8::new(2).zip(CheckedI64::new(-3)), Ok((2, -3)));
+    assert_eq!(
+        CheckedU8::new(2).zip(failed).map_err(|err| err.kind()),
+        Err(OverflowKind::Sub)
+    );
+    assert_eq!(CheckedU8::new(5).unwrap(), 5);
+}
+
+#[test]
+#[should_panic(expected = "price: attempt to subtract with overflow")]
+fn expect_panics() {
+    (CheckedU8::new(0) - 1).expect("price");
+}
+
+#[test]
+fn formatting() {
+    extern crate std;
+    use std::format;
+
+    let num = CheckedU16::new(255);
+    let failed = num * 300;
+
+    assert_eq!(
+        format!("{num:#06x}|{num:X}|{num:o}|{num:#b}"),
+        "0x00ff|FF|377|0b11111111"
+    );
+    assert_eq!(format!("{num:e}|{:E}", CheckedI8::new(-20)), "2.55e2|-2E1");
+    assert_eq!(
+        format!("{failed:*^14}|{failed:#x}"),
+        "**<overflow>**|<overflow>"
+    );
+    assert_eq!(format!("{:<4}|", failed.with_marker("-")), "-   |");
+    assert_eq!(
+        format!("{}", CheckedNonZeroU8::new(NonZero::new(9).unwrap())),
+        "9"
+    );
+}
+
+#[test]
+fn parse() {
+    let parse = |src: &str| src.parse::<CheckedI8>();
+
+    assert_eq!(parse("-0b1000_0000"), Ok(CheckedI8::new(-128)));
+    assert_eq!(parse("+0o17"), Ok(CheckedI8::new(15)));
+    assert_eq!(parse("-129").unwrap().saturate(), Some(i8::MIN));
+    assert_eq!(
+        parse("9999").unwrap().overflow_kind(),
+        Some(OverflowKind::Conversion)
+    );
+    // malformed input is an error even after an overflow
+    assert_eq!(
+        parse("9999x").unwrap_err().kind(),
+        ParseErrorKind::InvalidDigit
+    );
+    assert_eq!(parse("-").unwrap_err().kind(), ParseErrorKind::InvalidDigit);
+    assert_eq!(
+        parse("0x").unwrap_err().kind(),
+        ParseErrorKind::InvalidDigit
+    );
+    assert_eq!(
+        parse("1_").unwrap_err().kind(),
+        ParseErrorKind::InvalidSeparator
+    );
+
+    assert_eq!("-0".parse::<CheckedU8>(), Ok(CheckedU8::new(0)));
+    assert!("-1".parse::<CheckedU8>().unwrap().did_overflow());
+    assert_eq!(
+        "0".parse::<CheckedNonZeroU8>().unwrap().overflow_kind(),
+        Some(OverflowKind::Zero)
+    );
+}
+
+#[test]
+fn sum_and_product() {
+    let values = [1u32, 2, 3, 4];
+    let checked = values.map(CheckedU32::new);
+
+    assert_eq!(values.into_iter().sum::<CheckedU32>(), 10);
+    assert_eq!(values.iter().sum::<CheckedU32>(), 10);
+    assert_eq!(checked.into_iter().sum::<CheckedU32>(), 10);
+    assert_eq!(checked.iter().product::<CheckedU32>(), 24);
+    assert_eq!([0u32; 0].iter().product::<CheckedU32>(), 1);
+
+    // a failed item is propagated
+    let failed = [
+        CheckedU8::new(1),
+        CheckedU8::new(0) - 1,
+        CheckedU8::new(255) + 1,
+    ];
+    assert_eq!(
+        failed.iter().checked_sum::<u8>().overflow_kind(),
+        Some(OverflowKind::Sub)
+    );
+
+    // intermediate results may be zero, the final one may not
+    let non_zero = [1i8, -1, 5].map(|num| NonZero::new(num).unwrap());
+    assert_eq!(
+        non_zero.iter().checked_sum::<NonZero<i8>>().as_option(),
+        NonZero::new(5)
+    );
+    assert_eq!(
+        non_zero[..2]
+            .iter()
+            .checked_sum::<NonZero<i8>>()
+            .overflow_kind(),
+        Some(OverflowKind::Zero)
+    );
+
+    assert_eq!((0..255).checked_count::<u8>(), 255);
+    assert_eq!(
+        (0..).checked_count::<u8>().overflow_kind(),
+        Some(OverflowKind::Add)
+    );
+}
+
+#[test]
+fn total_order() {
+    extern crate std;
+    use std::collections::HashSet;
+
+    let failed_add = CheckedI8::new(127) + 1;
+    let failed_neg = -CheckedI8::new(-128);
+
+    assert_eq!(failed_add.total(), failed_neg.total());
+    assert_ne!(failed_add.total(), CheckedI8::new(127).total());
+    assert!(failed_add.total() > CheckedI8::new(127).total());
+    assert!(TotalCheckedNum::<_, true>::from(failed_add) < CheckedI8::new(-128).into());
+
+    let set: HashSet<_> = [failed_add, failed_neg, CheckedI8::new(1), CheckedI8::new(1)]
+        .map(CheckedI8::total)
+        .into();
+    assert_eq!(set.len(), 2);
+
+    // the error survives the round trip
+    assert_eq!(
+        CheckedI8::from(failed_neg.total()).overflow_kind(),
+        Some(OverflowKind::Neg)
+    );
+}
+
+#[test]
+fn min_max_clamp() {
+    let failed = CheckedU8::new(255) + 1;
+
+    assert_eq!(CheckedU8::new(7).min(CheckedU8::new(3)), 3);
+    assert_eq!(CheckedU8::new(7).max(3), 7);
+    assert!(CheckedU8::new(7).max(failed).did_overflow());
+    assert_eq!(
+        CheckedU8::new(7).clamp(0, failed).overflow_kind(),
+        Some(OverflowKind::Add)
+    );
+    assert_eq!(CheckedU8::new(7).clamp(7, 7), 7);
+
+    assert_eq!(CheckedI64::new(i64::MIN).abs_diff(i64::MAX), u64::MAX);
+    assert_eq!(CheckedU16::new(3).abs_diff(10), 7);
+    let non_zero = CheckedNonZeroI8::new(NonZero::new(5).unwrap());
+    assert_eq!(non_zero.abs_diff(non_zero), 0u8);
+
+    assert_eq!(CheckedI8::new(-128).midpoint(127), 0);
+    assert_eq!(
+        CheckedNonZeroI8::new(NonZero::new(-1).unwrap())
+            .midpoint(NonZero::new(1).unwrap())
+            .overflow_kind(),
+        Some(OverflowKind::Zero)
+    );
+}
+
+#[test]
+#[should_panic = "clamp requires lo <= hi"]
+fn clamp_panics() {
+    let _ = CheckedU8::new(7).clamp(10, 5);
+}
+
+#[test]
+fn sign_ops() {
+    assert_eq!(CheckedI16::new(-7).abs(), 7);
+    assert_eq!(CheckedU16::new(7).abs(), 7);
+    assert_eq!(CheckedI16::new(i16::MIN).abs().saturate(), Some(i16::MAX));
+    assert_eq!(CheckedI16::new(9).signum(), 1);
+    assert_eq!(CheckedU16::new(0).signum(), 0);
+
+    let failed = CheckedI16::new(i16::MIN) - 1;
+    assert!(CheckedI16::new(-1).is_negative());
+    assert!(!CheckedI16::new(0).is_positive());
+    assert!(!failed.is_negative() && !failed.is_positive());
+
+    assert_eq!(CheckedI16::new(i16::MIN).unsigned_abs(), 32768u16);
+    let non_zero = CheckedNonZeroI16::new(NonZero::new(-3).unwrap());
+    assert_eq!(non_zero.unsigned_abs().as_option(), NonZero::new(3u16));
+    assert_eq!(non_zero.abs().as_option(), NonZero::new(3));
+
+    let balance = CheckedU32::new(10);
+    assert_eq!(balance.add_signed(-10), 0);
+    assert_eq!(balance.add_signed(5), 15);
+    assert_eq!(balance.add_signed(-11).saturate(), Some(0));
+    assert_eq!(
+        CheckedU32::new(u32::MAX).add_signed(1).saturate(),
+        Some(u32::MAX)
+    );
+    assert!(balance.add_signed(failed.cast::<i32>()).did_overflow());
+
+    assert_eq!(CheckedI8::new(-128).add_unsigned(255u8), 127);
+    assert!(CheckedI8::new(-127).add_unsigned(255u8).did_overflow());
+    assert_eq!(CheckedI8::new(127).sub_unsigned(255u8), -128);
+    assert_eq!(
+        CheckedI8::new(126).sub_unsigned(255u8).overflow_kind(),
+        Some(OverflowKind::Sub)
+    );
+}
+
+#[test]
+fn mixed_widths() {
+    let small = CheckedU8::new(200);
+    let large = CheckedU32::new(100_000);
+
+    assert_eq!(small + large.promote(), 100_200u32);
+    assert_eq!(large - small.promote(), 99_800u32);
+    assert_eq!(small + Promote::new(100_000u32), 100_200u32);
+    assert_eq!(CheckedI8::new(-2) * Promote::new(3i64), -6i64);
+    assert_eq!(CheckedU16::new(7) % Promote::new(-4i32), 3i32);
+    assert_eq!(CheckedU8::new(7) + CheckedU8::new(3).promote(), 10);
+
+    let mut sum = CheckedU64::new(0);
+    sum += small.promote();
+    sum += Promote::new(300u16);
+    assert_eq!(sum, 500u64);
+
+    // failures of either operand are kept
+    assert_eq!(
+        ((small + 100) + large.promote()).overflow_kind(),
+        Some(OverflowKind::Add)
+    );
+
+    // untyped operands are still inferred from the other operand
+    let x = 3;
+    assert!((CheckedU16::new(u16::MAX) + CheckedNum::new(x)).did_overflow());
+
+    let mut narrowed = small;
+    narrowed -= large.narrow();
+    assert_eq!(narrowed.overflow_kind(), Some(OverflowKind::Conversion));
+    assert_eq!(
+        (small / Narrow::new(-4i64)).overflow_kind(),
+        Some(OverflowKind::Conversion)
+    );
+    assert_eq!(small - CheckedI16::new(100).narrow(), 100);
+}
@@ -0,0 +1,161 @@
+use core::{error::Error, fmt};
+
+#[cfg(feature = "location")]
+use core::panic::Location;
+
+/// The operation that caused a `CheckedNum` to fail.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowKind {
+    /// Addition overflowed.
+    Add,
+    /// Subtraction overflowed.
+    Sub,
+    /// Multiplication overflowed.
+    Mul,
+    /// Division overflowed (`MIN / -1`).
+    Div,
+    /// Remainder overflowed (`MIN % -1`).
+    Rem,
+    /// Division or remainder by zero.
+    DivByZero,
+    /// Negation overflowed (`-MIN`, or negating a non-zero unsigned value).
+    Neg,
+    /// Left shift lost significant bits or changed the sign.
+    Shl,
+    /// Shift amount is greater than or equal to the bit width.
+    ShiftTooLarge,
+    /// Exponentiation overflowed.
+    Pow,
+    /// The result of a `NonZero<_>` operation was zero.
+    Zero,
+    /// The value does not fit into the target type of a conversion.
+    Conversion,
+    /// A float to integer conversion of NaN.
+    NaN,
+    /// The integer can't be represented exactly as a float.
+    Inexact,
+    /// The value was rejected by [`CheckedNum::filter`](crate::CheckedNum::filter).
+    Filtered,
+    /// The value was created from `None` without further information.
+    Unknown,
+}
+
+impl fmt::Display for OverflowKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OverflowKind::Add => "attempt to add with overflow",
+            OverflowKind::Sub => "attempt to subtract with overflow",
+            OverflowKind::Mul => "attempt to multiply with overflow",
+            OverflowKind::Div => "attempt to divide with overflow",
+            OverflowKind::Rem => "attempt to calculate the remainder with overflow",
+            OverflowKind::DivByZero => "attempt to divide by zero",
+            OverflowKind::Neg => "attempt to negate with overflow",
+            OverflowKind::Shl => "attempt to shift left with overflow",
+            OverflowKind::ShiftTooLarge => "attempt to shift by at least the bit width",
+            OverflowKind::Pow => "attempt to raise to a power with overflow",
+            OverflowKind::Zero => "attempt to create a zero value of a non-zero type",
+            OverflowKind::Conversion => "value out of range for the target type",
+            OverflowKind::NaN => "attempt to convert NaN to an integer",
+            OverflowKind::Inexact => "value can not be represented exactly as a float",
+            OverflowKind::Filtered => "value rejected by a filter",
+            OverflowKind::Unknown => "overflow in an unknown operation",
+        })
+    }
+}
+
+/// Whether the true result of a failed operation was above or below the range of the type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowDirection {
+    /// The true result was greater than `MAX`.
+    Positive,
+    /// The true result was less than `MIN`.
+    Negative,
+    /// The result is not defined (e.g. division by zero) or is inside the range of the type
+    /// but still not representable (e.g. zero for `NonZero<_>` types).
+    Undefined,
+}
+
+/// The failed state of a `CheckedNum`.
+///
+/// Records why the first failing operation failed.
+/// It is kept unchanged as the failure propagates through subsequent calculations.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedU8, OverflowKind};
+///
+/// let err = (CheckedU8::new(1) / 0 + 1).into_result().unwrap_err();
+///
+/// assert_eq!(err.kind(), OverflowKind::DivByZero);
+/// assert!(err.to_string().starts_with("attempt to divide by zero"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OverflowError {
+    kind: OverflowKind,
+    direction: OverflowDirection,
+    #[cfg(feature = "location")]
+    location: &'static Location<'static>,
+}
+
+impl OverflowError {
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn new(kind: OverflowKind, direction: OverflowDirection) -> Self {
+        Self {
+            kind,
+            direction,
+            #[cfg(feature = "location")]
+            location: Location::caller(),
+        }
+    }
+
+    pub fn kind(&self) -> OverflowKind {
+        self.kind
+    }
+
+    pub fn direction(&self) -> OverflowDirection {
+        self.direction
+    }
+
+    /// The source location of the failing operation.
+    #[cfg(feature = "location")]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Records the location of the caller as the location of the failing operation.
+    #[cfg(feature = "location")]
+    #[track_caller]
+    pub(crate) fn at_caller(self) -> Self {
+        Self {
+            location: Location::caller(),
+            ..self
+        }
+    }
+
+    #[cfg(not(feature = "location"))]
+    pub(crate) fn at_caller(self) -> Self {
+        self
+    }
+}
+
+/// Creates an error with an [`OverflowDirection::Undefined`] direction.
+impl From<OverflowKind> for OverflowError {
+    #[cfg_attr(feature = "location", track_caller)]
+    fn from(kind: OverflowKind) -> Self {
+        Self::new(kind, OverflowDirection::Undefined)
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)?;
+
+        #[cfg(feature = "location")]
+        write!(f, " at {}", self.location)?;
+
+        Ok(())
+    }
+}
+
+impl Error for OverflowError {}
@@ -0,0 +1,170 @@
+use core::{error::Error, fmt, str::FromStr};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Zero};
+
+use crate::{
+    CheckedNum,
+    checked_num::CheckedNumTraits,
+    overflow::{OverflowDirection, OverflowError, OverflowKind},
+};
+
+/// Malformed input to [`CheckedNum::from_str_radix`] or `str::parse`.
+///
+/// Syntactically valid numbers that don't fit into the type
+/// are not an error, they parse into a failed `CheckedNum`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+}
+
+/// The reason a string could not be parsed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// The string is empty.
+    Empty,
+    /// The string contains a character that is not a digit of the radix,
+    /// or no digits after the sign or prefix.
+    InvalidDigit,
+    /// A `_` separator at the start or end of the digits.
+    InvalidSeparator,
+}
+
+impl ParseError {
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl From<ParseErrorKind> for ParseError {
+    fn from(kind: ParseErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Empty => "cannot parse integer from empty string",
+            ParseErrorKind::InvalidDigit => "invalid digit found in string",
+            ParseErrorKind::InvalidSeparator => "separator `_` at the start or end of the digits",
+        })
+    }
+}
+
+impl Error for ParseError {}
+
+impl<T: CheckedNumTraits> CheckedNum<T> {
+    /// Parses an integer with an optional sign in the given radix.
+    ///
+    /// Digits may be separated by `_`.
+    /// Numbers out of the range of `T` parse into a failed value
+    /// with [`OverflowKind::Conversion`].
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range `2..=36`.
+    ///
+    /// ```rust
+    /// use checked_num::{CheckedI8, CheckedU8, ParseErrorKind};
+    ///
+    /// assert_eq!(CheckedU8::from_str_radix("1111_1111", 2), Ok(CheckedU8::new(255)));
+    /// assert_eq!(CheckedI8::from_str_radix("-80", 16), Ok(CheckedI8::new(-128)));
+    /// assert!(CheckedU8::from_str_radix("100", 16).unwrap().did_overflow());
+    ///
+    /// assert_eq!(
+    ///     CheckedU8::from_str_radix("12g", 16).unwrap_err().kind(),
+    ///     ParseErrorKind::InvalidDigit
+    /// );
+    /// ```
+    #[cfg_attr(feature = "location", track_caller)]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36, got {radix}"
+        );
+
+        let (is_negative, digits) = split_sign(src)?;
+        Self::parse_digits(is_negative, digits, radix)
+    }
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn parse_digits(is_negative: bool, digits: &str, radix: u32) -> Result<Self, ParseError> {
+        if digits.is_empty() {
+            return Err(ParseErrorKind::InvalidDigit.into());
+        }
+
+        if digits.starts_with('_') || digits.ends_with('_') {
+            return Err(ParseErrorKind::InvalidSeparator.into());
+        }
+
+        // `radix` and all digits are at most 36, which fits into every integer type
+        let to_primitive = |num| T::Primitive::from_u32(num).unwrap();
+
+        // `None` once the value is out of range, the remaining digits are still validated
+        let mut acc = Some(T::Primitive::zero());
+
+        for char in digits.chars().filter(|&char| char != '_') {
+            let digit = to_primitive(char.to_digit(radix).ok_or(ParseErrorKind::InvalidDigit)?);
+
+            acc = acc
+                .and_then(|acc| acc.checked_mul(&to_primitive(radix)))
+                .and_then(|acc| match is_negative {
+                    true => acc.checked_sub(&digit),
+                    false => acc.checked_add(&digit),
+                });
+        }
+
+        let direction = match is_negative {
+            true => OverflowDirection::Negative,
+            false => OverflowDirection::Positive,
+        };
+
+        let result = acc.ok_or(OverflowError::new(OverflowKind::Conversion, direction));
+
+        Ok(Self::from_primitive_result(result))
+    }
+}
+
+/// Parses an integer with an optional sign and an optional `0x`, `0o` or `0b` prefix.
+///
+/// See [`CheckedNum::from_str_radix`].
+///
+/// ```rust
+/// use checked_num::{CheckedU16, ParseErrorKind};
+///
+/// let parse = |src: &str| src.parse::<CheckedU16>();
+///
+/// assert_eq!(parse("65_535"), Ok(CheckedU16::new(u16::MAX)));
+/// assert_eq!(parse("0xff"), Ok(CheckedU16::new(255)));
+/// assert!(parse("65_536").unwrap().did_overflow());
+/// assert_eq!(parse("").unwrap_err().kind(), ParseErrorKind::Empty);
+/// ```
+impl<T: CheckedNumTraits> FromStr for CheckedNum<T> {
+    type Err = ParseError;
+
+    #[cfg_attr(feature = "location", track_caller)]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let (is_negative, rest) = split_sign(src)?;
+
+        let (radix, digits) = match rest.get(..2) {
+            Some("0x") => (16, &rest[2..]),
+            Some("0o") => (8, &rest[2..]),
+            Some("0b") => (2, &rest[2..]),
+            _ => (10, rest),
+        };
+
+        Self::parse_digits(is_negative, digits, radix)
+    }
+}
+
+/// Splits off an optional `+` or `-` sign.
+fn split_sign(src: &str) -> Result<(bool, &str), ParseError> {
+    if src.is_empty() {
+        return Err(ParseErrorKind::Empty.into());
+    }
+
+    Ok(match src.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, src.strip_prefix('+').unwrap_or(src)),
+    })
+}
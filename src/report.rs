@@ -0,0 +1,143 @@
+use core::{
+    error::Error,
+    fmt::{self, Write},
+};
+
+use crate::OverflowError;
+
+/// Describes the first failing operation of an `explain!` expression.
+///
+/// Holds the failing sub-expression, its source text and the values of its operands.
+/// The values are stored inline, no allocation is needed.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{OverflowKind, explain};
+///
+/// let (qty, unit_price, fee) = (3_000_000_000u32, 2, 5);
+///
+/// let report = explain!(qty * unit_price + fee).unwrap_err();
+///
+/// assert_eq!(report.error().kind(), OverflowKind::Mul);
+/// assert_eq!(report.expr(), "qty * unit_price");
+/// assert!(report.operands().eq([("qty", "3000000000"), ("unit_price", "2")]));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OverflowReport {
+    error: OverflowError,
+    expr: &'static str,
+    // Sources and values are stored in separate arrays, which avoids padding.
+    sources: [&'static str; 2],
+    values: [FormattedValue; 2],
+    operand_count: u8,
+}
+
+/// Fixed-size storage for the decimal representation of any builtin integer.
+#[derive(Clone, Copy)]
+struct FormattedValue {
+    // `i128::MIN` has 40 characters.
+    buf: [u8; 40],
+    len: u8,
+}
+
+impl OverflowReport {
+    pub(crate) fn new(error: OverflowError, expr: &'static str) -> Self {
+        Self {
+            error,
+            expr,
+            sources: [""; 2],
+            values: [FormattedValue {
+                buf: [0; 40],
+                len: 0,
+            }; 2],
+            operand_count: 0,
+        }
+    }
+
+    /// Adds an operand of the failing operation, ignored if there are already two.
+    pub(crate) fn with_operand(mut self, source: &'static str, value: impl fmt::Display) -> Self {
+        let index = usize::from(self.operand_count);
+
+        if let (Some(slot), Some(formatted)) =
+            (self.sources.get_mut(index), self.values.get_mut(index))
+        {
+            *slot = source;
+            // Builtin integers always fit.
+            let _ = write!(formatted, "{value}");
+            self.operand_count += 1;
+        }
+
+        self
+    }
+
+    /// The error of the failing operation.
+    pub fn error(&self) -> OverflowError {
+        self.error
+    }
+
+    /// The source text of the failing sub-expression.
+    pub fn expr(&self) -> &'static str {
+        self.expr
+    }
+
+    /// The source text and value of each operand of the failing operation.
+    ///
+    /// Empty if the sub-expression was a `CheckedNum` that had already failed.
+    pub fn operands(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.sources
+            .iter()
+            .zip(&self.values)
+            .take(self.operand_count.into())
+            .map(|(source, value)| (*source, value.as_str()))
+    }
+}
+
+impl fmt::Display for OverflowReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in `{}`", self.error, self.expr)?;
+
+        for (i, (source, value)) in self.operands().enumerate() {
+            let separator = if i == 0 { " (" } else { ", " };
+            write!(f, "{separator}{source} = {value}")?;
+        }
+
+        if self.operands().next().is_some() {
+            f.write_char(')')?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for OverflowReport {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl FormattedValue {
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len.into()]).unwrap_or_default()
+    }
+}
+
+impl Write for FormattedValue {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let start = usize::from(self.len);
+        let end = start + s.len();
+        self.buf
+            .get_mut(start..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        // `end` is at most 40
+        self.len = end as u8;
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FormattedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
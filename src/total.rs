@@ -0,0 +1,123 @@
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{CheckedNum, checked_num::CheckedNumTraits};
+
+/// A `CheckedNum` with a total order, for sorting and as a map key.
+///
+/// All failed values are equal to each other regardless of their error,
+/// and sort after every valid value, or before if `FAILED_FIRST` is `true`.
+///
+/// Created by [`CheckedNum::total`] or through `From`, which keeps the error.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedU8, TotalCheckedNum};
+///
+/// let failed = CheckedU8::new(255) + 1;
+/// let mut values = [CheckedU8::new(3), failed, CheckedU8::new(1)].map(CheckedU8::total);
+///
+/// values.sort();
+/// assert_eq!(values.map(|num| num.get().as_option()), [Some(1), Some(3), None]);
+///
+/// let mut values = values.map(TotalCheckedNum::<u8, true>::from);
+///
+/// values.sort();
+/// assert_eq!(values.map(|num| num.get().as_option()), [None, Some(1), Some(3)]);
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct TotalCheckedNum<T: CheckedNumTraits, const FAILED_FIRST: bool = false>(CheckedNum<T>);
+
+impl<T: CheckedNumTraits> CheckedNum<T> {
+    /// Wraps the value in a [`TotalCheckedNum`], sorting failed values last.
+    pub fn total(self) -> TotalCheckedNum<T> {
+        TotalCheckedNum(self)
+    }
+}
+
+impl<T: CheckedNumTraits, const FAILED_FIRST: bool> TotalCheckedNum<T, FAILED_FIRST> {
+    pub fn new(num: CheckedNum<T>) -> Self {
+        Self(num)
+    }
+
+    /// The wrapped `CheckedNum`, including its error.
+    pub fn get(self) -> CheckedNum<T> {
+        self.0
+    }
+}
+
+impl<T: CheckedNumTraits, const FAILED_FIRST: bool> From<CheckedNum<T>>
+    for TotalCheckedNum<T, FAILED_FIRST>
+{
+    fn from(num: CheckedNum<T>) -> Self {
+        Self(num)
+    }
+}
+
+impl<T: CheckedNumTraits, const FAILED_FIRST: bool> From<TotalCheckedNum<T, FAILED_FIRST>>
+    for CheckedNum<T>
+{
+    fn from(num: TotalCheckedNum<T, FAILED_FIRST>) -> Self {
+        num.0
+    }
+}
+
+/// Switches between sorting failed values first and last.
+impl<T: CheckedNumTraits> From<TotalCheckedNum<T, false>> for TotalCheckedNum<T, true> {
+    fn from(num: TotalCheckedNum<T, false>) -> Self {
+        Self(num.0)
+    }
+}
+
+/// Switches between sorting failed values first and last.
+impl<T: CheckedNumTraits> From<TotalCheckedNum<T, true>> for TotalCheckedNum<T, false> {
+    fn from(num: TotalCheckedNum<T, true>) -> Self {
+        Self(num.0)
+    }
+}
+
+impl<T: CheckedNumTraits + Ord, const FAILED_FIRST: bool> PartialEq
+    for TotalCheckedNum<T, FAILED_FIRST>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<T: CheckedNumTraits + Ord, const FAILED_FIRST: bool> Eq for TotalCheckedNum<T, FAILED_FIRST> {}
+
+impl<T: CheckedNumTraits + Ord, const FAILED_FIRST: bool> PartialOrd
+    for TotalCheckedNum<T, FAILED_FIRST>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: CheckedNumTraits + Ord, const FAILED_FIRST: bool> Ord for TotalCheckedNum<T, FAILED_FIRST> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let failed = match FAILED_FIRST {
+            true => Ordering::Less,
+            false => Ordering::Greater,
+        };
+
+        match (self.0.as_option(), other.0.as_option()) {
+            (Some(num), Some(other)) => num.cmp(&other),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => failed,
+            (Some(_), None) => failed.reverse(),
+        }
+    }
+}
+
+// All failed values hash alike, consistent with `Eq`.
+impl<T: CheckedNumTraits + Hash, const FAILED_FIRST: bool> Hash
+    for TotalCheckedNum<T, FAILED_FIRST>
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_option().hash(state);
+    }
+}
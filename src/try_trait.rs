@@ -0,0 +1,57 @@
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+use crate::{CheckedNum, OverflowError, checked_num::CheckedNumTraits};
+
+/// Enables the `?` operator on `CheckedNum` (requires the `nightly` feature).
+///
+/// The failure can be propagated into functions returning `CheckedNum`, `Option`
+/// or `Result<_, E>` where `E: From<OverflowError>`.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedU8, OverflowKind};
+///
+/// fn double(num: CheckedU8) -> Option<u8> {
+///     Some(num? * 2)
+/// }
+///
+/// assert_eq!(double(CheckedU8::new(4)), Some(8));
+/// assert_eq!(double(CheckedU8::new(255) + 1), None);
+/// ```
+impl<T: CheckedNumTraits> Try for CheckedNum<T> {
+    type Output = T;
+    type Residual = OverflowError;
+
+    fn from_output(output: Self::Output) -> Self {
+        Self::new(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self.into_result() {
+            Ok(num) => ControlFlow::Continue(num),
+            Err(err) => ControlFlow::Break(err),
+        }
+    }
+}
+
+impl<T: CheckedNumTraits> Residual<T> for OverflowError {
+    type TryType = CheckedNum<T>;
+}
+
+impl<T: CheckedNumTraits> FromResidual<OverflowError> for CheckedNum<T> {
+    fn from_residual(residual: OverflowError) -> Self {
+        Self::overflowed(residual)
+    }
+}
+
+impl<T> FromResidual<OverflowError> for Option<T> {
+    fn from_residual(_: OverflowError) -> Self {
+        None
+    }
+}
+
+impl<T, E: From<OverflowError>> FromResidual<OverflowError> for Result<T, E> {
+    fn from_residual(residual: OverflowError) -> Self {
+        Err(residual.into())
+    }
+}
@@ -0,0 +1,135 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use crate::{CheckedNum, checked_num::CheckedNumTraits};
+
+/// A right-hand side operand whose operation is performed in the wider of both types.
+///
+/// Integers convert into the wider type without loss, so only the operation itself can fail.
+/// Only lossless combinations are implemented, e.g. `CheckedU8` and `Promote<u32>`,
+/// but not `CheckedU32` and `Promote<i8>`.
+///
+/// Created by [`CheckedNum::promote`], or by [`Promote::new`] for builtin integers.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedU8, CheckedU32, Promote};
+///
+/// let a = CheckedU8::new(200);
+///
+/// assert_eq!(a + Promote::new(100_000u32), 100_200u32);
+/// assert_eq!(CheckedU32::new(7) * a.promote(), 1400u32);
+///
+/// let mut total = CheckedU32::new(0);
+/// total += a.promote();
+/// assert_eq!(total, 200u32);
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct Promote<T: CheckedNumTraits>(pub(crate) CheckedNum<T>);
+
+impl<T: CheckedNumTraits> Promote<T> {
+    pub fn new(num: T) -> Self {
+        Self(CheckedNum::new(num))
+    }
+}
+
+/// A right-hand side operand that is converted to the width of the left-hand side.
+///
+/// Instead of promoting the result to the wider type,
+/// the operation fails with [`OverflowKind::Conversion`](crate::OverflowKind::Conversion)
+/// if the operand doesn't fit into the left-hand side.
+///
+/// Created by [`CheckedNum::narrow`], or by [`Narrow::new`] for builtin integers.
+///
+/// Example:
+/// ```rust
+/// use checked_num::{CheckedU8, CheckedU32, Narrow, OverflowKind};
+///
+/// let a = CheckedU8::new(200);
+///
+/// // keeps the width of `a`
+/// assert_eq!(a + CheckedU32::new(50).narrow(), 250u8);
+/// assert_eq!(a + Narrow::new(50u32), 250u8);
+/// assert_eq!(
+///     (a - Narrow::new(300u32)).overflow_kind(),
+///     Some(OverflowKind::Conversion)
+/// );
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct Narrow<T: CheckedNumTraits>(CheckedNum<T>);
+
+impl<T: CheckedNumTraits> Narrow<T> {
+    pub fn new(num: T) -> Self {
+        Self(CheckedNum::new(num))
+    }
+}
+
+impl<T: CheckedNumTraits> CheckedNum<T> {
+    /// Uses the value as an operand that is combined in the wider of both types.
+    ///
+    /// See [`Promote`].
+    pub fn promote(self) -> Promote<T> {
+        Promote(self)
+    }
+
+    /// Uses the value as an operand that takes the width of the left-hand side.
+    ///
+    /// See [`Narrow`].
+    pub fn narrow(self) -> Narrow<T> {
+        Narrow(self)
+    }
+}
+
+// The combinations of different widths are implemented next to the lossless casts.
+macro_rules! impl_width_op {
+    ($($trait:ident, $trait_fn:ident, $assign_trait:ident, $assign_fn:ident);*) => {
+        $(
+            impl<T: CheckedNumTraits> $trait<Promote<T>> for CheckedNum<T> {
+                type Output = Self;
+
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $trait_fn(self, rhs: Promote<T>) -> Self::Output {
+                    self.$trait_fn(rhs.0)
+                }
+            }
+
+            impl<T: CheckedNumTraits> $assign_trait<Promote<T>> for CheckedNum<T> {
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $assign_fn(&mut self, rhs: Promote<T>) {
+                    *self = self.$trait_fn(rhs);
+                }
+            }
+
+            impl<T: CheckedNumTraits, U: CheckedNumTraits> $trait<Narrow<U>> for CheckedNum<T>
+            where
+                T::Primitive: TryFrom<U::Primitive>,
+            {
+                type Output = Self;
+
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $trait_fn(self, rhs: Narrow<U>) -> Self::Output {
+                    self.$trait_fn(rhs.0.cast::<T>())
+                }
+            }
+
+            impl<T: CheckedNumTraits, U: CheckedNumTraits> $assign_trait<Narrow<U>> for CheckedNum<T>
+            where
+                T::Primitive: TryFrom<U::Primitive>,
+            {
+                #[cfg_attr(feature = "location", track_caller)]
+                fn $assign_fn(&mut self, rhs: Narrow<U>) {
+                    *self = self.$trait_fn(rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_width_op! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign
+}